@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use common::bench::{Stats, Timings};
+use common::solution;
+use serde::Serialize;
+
+use crate::days::Day;
+
+#[derive(Serialize)]
+struct StatsRecord {
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+}
+
+impl From<Stats> for StatsRecord {
+    fn from(stats: Stats) -> Self {
+        Self {
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DayRecord {
+    day: u8,
+    parse: StatsRecord,
+    part_one: StatsRecord,
+    part_two: StatsRecord,
+}
+
+#[derive(Serialize)]
+struct Report {
+    runs: usize,
+    days: Vec<DayRecord>,
+}
+
+fn print_stage(name: &str, stats: &Stats) {
+    println!(
+        "  {:<8} min {:>10.1?}  median {:>10.1?}  mean {:>10.1?}",
+        name, stats.min, stats.median, stats.mean
+    );
+}
+
+/// Benchmark each of `days` on its input and optionally write the results as JSON to `json_path`.
+/// Returns false if any day failed to run.
+pub fn run(days: &[&Day], runs: usize, json_path: Option<&str>) -> bool {
+    let mut failed = false;
+    let mut records = Vec::new();
+    let mut total = Duration::ZERO;
+
+    for day in days {
+        println!("Day {:02}", day.number);
+        let timings: Timings = match crate::input_path(day)
+            .and_then(|path| solution::bench_file(day.solution, &path, runs))
+        {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+                continue;
+            }
+        };
+
+        print_stage("parse", &timings.parse);
+        print_stage("part one", &timings.part_one);
+        print_stage("part two", &timings.part_two);
+        total += timings.parse.median + timings.part_one.median + timings.part_two.median;
+
+        records.push(DayRecord {
+            day: day.number,
+            parse: timings.parse.into(),
+            part_one: timings.part_one.into(),
+            part_two: timings.part_two.into(),
+        });
+    }
+    println!("Total (sum of medians): {:.1?}", total);
+
+    if let Some(path) = json_path {
+        let report = Report {
+            runs,
+            days: records,
+        };
+        let written = serde_json::to_string_pretty(&report)
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(path, json + "\n").map_err(|e| e.to_string()));
+        if let Err(e) = written {
+            eprintln!("{}: Unable to write benchmark results: {}", path, e);
+            failed = true;
+        }
+    }
+
+    !failed
+}
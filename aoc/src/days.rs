@@ -0,0 +1,85 @@
+use common::Runnable;
+
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn Runnable,
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &problem_01::Problem,
+    },
+    Day {
+        number: 2,
+        solution: &problem_02::Problem,
+    },
+    Day {
+        number: 3,
+        solution: &problem_03::Problem,
+    },
+    Day {
+        number: 4,
+        solution: &problem_04::Problem,
+    },
+    Day {
+        number: 5,
+        solution: &problem_05::Problem,
+    },
+    Day {
+        number: 6,
+        solution: &problem_06::Problem,
+    },
+    Day {
+        number: 7,
+        solution: &problem_07::Problem,
+    },
+    Day {
+        number: 8,
+        solution: &problem_08::Problem,
+    },
+    Day {
+        number: 9,
+        solution: &problem_09::Problem,
+    },
+    Day {
+        number: 10,
+        solution: &problem_10::Problem,
+    },
+    Day {
+        number: 11,
+        solution: &problem_11::Problem,
+    },
+    Day {
+        number: 12,
+        solution: &problem_12::Problem,
+    },
+    Day {
+        number: 13,
+        solution: &problem_13::Problem,
+    },
+    Day {
+        number: 14,
+        solution: &problem_14::Problem,
+    },
+    Day {
+        number: 15,
+        solution: &problem_15::Problem,
+    },
+    Day {
+        number: 16,
+        solution: &problem_16::Problem,
+    },
+    Day {
+        number: 17,
+        solution: &problem_17::Problem,
+    },
+    Day {
+        number: 18,
+        solution: &problem_18::Problem,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,314 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const YEAR: u16 = 2021;
+
+/// Minimum time between two requests to the server, shared between runs.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A response from the puzzle server.
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP requests needed by the fetcher, so that tests can stand in for the real server.
+pub trait HttpClient {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, String>;
+}
+
+pub struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, String> {
+        let result = ureq::get(url)
+            .set("Cookie", cookie)
+            .set(
+                "User-Agent",
+                "aoc2021_rust input fetcher (kim@wayoftao.net)",
+            )
+            .call();
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e.to_string()),
+        };
+        let status = response.status();
+        let body = response.into_string().map_err(|e| e.to_string())?;
+
+        Ok(Response { status, body })
+    }
+}
+
+/// Check that a response actually contains a puzzle input.
+fn validate(response: &Response) -> Result<(), String> {
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or("").trim();
+        return Err(format!(
+            "Server responded with status {}: {}",
+            response.status, reason
+        ));
+    }
+
+    let body = response.body.trim_start();
+    if body.is_empty() {
+        return Err("Server sent an empty input".to_owned());
+    }
+    if body.starts_with('<') || body.to_ascii_lowercase().contains("<html") {
+        return Err("Server sent an HTML page instead of a puzzle input".to_owned());
+    }
+
+    Ok(())
+}
+
+/// Downloads puzzle inputs, keeping a copy of each in a per year/day cache.
+pub struct Fetcher<C: HttpClient> {
+    client: C,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, base_url: &str, session: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            cache_dir: cache_dir.into(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day_{:02}.txt", day))
+    }
+
+    /// Sleep until `min_interval` has passed since the last request, then record this one.
+    /// The time of the last request is kept in the cache so that separate runs share the limit.
+    fn wait_for_slot(&self) -> Result<(), String> {
+        let stamp = self.cache_dir.join("last_request");
+        let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(elapsed) = last.and_then(|last| now().checked_sub(last)) {
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        write_file(&stamp, &now().as_millis().to_string())
+    }
+
+    /// Return the input for `day` of `year`, downloading it unless it is already cached.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let cached = self.cache_path(year, day);
+        if let Ok(data) = fs::read_to_string(&cached) {
+            return Ok(data);
+        }
+        if self.session.is_empty() {
+            return Err("No session cookie available to fetch inputs with".to_owned());
+        }
+
+        self.wait_for_slot()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .client
+            .get(&url, &format!("session={}", self.session))
+            .and_then(|response| validate(&response).map(|_| response))
+            .map_err(|e| format!("{}: {}", url, e))?;
+
+        write_file(&cached, &response.body)?;
+        Ok(response.body)
+    }
+}
+
+/// Write `data` to `path` through a temporary file, so that a failed write never leaves a
+/// truncated file behind.
+fn write_file(path: &Path, data: &str) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("{}: {}", path.display(), e);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(error)?;
+    }
+
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, data).map_err(error)?;
+    fs::rename(&temporary, path).map_err(error)
+}
+
+/// Read the session cookie from `AOC_SESSION`, or from `.session-cookie` in the workspace root.
+fn session_cookie() -> Result<String, String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session);
+    }
+
+    fs::read_to_string(crate::workspace_root().join(".session-cookie")).map_err(|e| {
+        format!(
+            "Unable to read session cookie from AOC_SESSION or .session-cookie: {}",
+            e
+        )
+    })
+}
+
+/// Whether `path` holds an input. `cargo xtask new-day` leaves an empty `input.txt` behind,
+/// which counts as missing.
+fn has_input(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Download the input for `day` to `path` unless it already exists.
+pub fn ensure_input(day: u8, path: &str) -> Result<(), String> {
+    if has_input(Path::new(path)) {
+        return Ok(());
+    }
+
+    let fetcher = Fetcher::new(
+        UreqClient,
+        "https://adventofcode.com",
+        &session_cookie()?,
+        crate::workspace_root().join(".cache/inputs"),
+    );
+    let data = fetcher.fetch(YEAR, day)?;
+
+    eprintln!("Fetched input for day {} to {}", day, path);
+    write_file(Path::new(path), &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    /// Serve canned responses for days 1-3 on a local port, counting the requests made.
+    fn stand_in_server() -> (String, Arc<AtomicUsize>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = requests.clone();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|h| h.field.equiv("Cookie") && h.value == "session=secret");
+                let (status, body) = match (authorized, request.url()) {
+                    (false, _) => (
+                        400,
+                        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                    ),
+                    (true, "/2021/day/1/input") => (200, "199\n200\n208\n"),
+                    (true, "/2021/day/2/input") => {
+                        (200, "<!DOCTYPE html>\n<html><body>Oops</body></html>\n")
+                    }
+                    _ => (
+                        404,
+                        "Please don't repeatedly request this endpoint before it unlocks!\n",
+                    ),
+                };
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let (url, requests) = stand_in_server();
+        let dir = cache_dir("cache");
+        let mut fetcher = Fetcher::new(UreqClient, &url, "secret\n", &dir);
+        fetcher.min_interval = Duration::ZERO;
+
+        assert_eq!(fetcher.fetch(2021, 1).unwrap(), "199\n200\n208\n");
+        assert_eq!(fetcher.fetch(2021, 1).unwrap(), "199\n200\n208\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2021/day_01.txt")).unwrap(),
+            "199\n200\n208\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reject_bad_responses() {
+        let (url, _) = stand_in_server();
+        let dir = cache_dir("reject");
+        let mut fetcher = Fetcher::new(UreqClient, &url, "secret", &dir);
+        fetcher.min_interval = Duration::ZERO;
+
+        let e = fetcher.fetch(2021, 2).unwrap_err();
+        assert!(
+            e.ends_with("Server sent an HTML page instead of a puzzle input"),
+            "{}",
+            e
+        );
+        let e = fetcher.fetch(2021, 3).unwrap_err();
+        assert!(e.contains("status 404"), "{}", e);
+        assert!(!dir.join("2021/day_02.txt").exists());
+        assert!(!dir.join("2021/day_03.txt").exists());
+
+        let mut fetcher = Fetcher::new(UreqClient, &url, "wrong", &dir);
+        fetcher.min_interval = Duration::ZERO;
+        let e = fetcher.fetch(2021, 1).unwrap_err();
+        assert!(
+            e.contains("status 400: Puzzle inputs differ by user."),
+            "{}",
+            e
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rate_limit() {
+        let (url, requests) = stand_in_server();
+        let dir = cache_dir("rate");
+        let mut fetcher = Fetcher::new(UreqClient, &url, "secret", &dir);
+        fetcher.min_interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        fetcher.fetch(2021, 2).unwrap_err();
+        fetcher.fetch(2021, 3).unwrap_err();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn empty_input_is_missing() {
+        let dir = cache_dir("empty");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        assert!(!has_input(&path));
+
+        fs::write(&path, "").unwrap();
+        assert!(!has_input(&path));
+
+        fs::write(&path, "1\n").unwrap();
+        assert!(has_input(&path));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
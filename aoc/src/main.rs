@@ -0,0 +1,279 @@
+mod bench;
+mod days;
+mod fetch;
+mod verify;
+
+use std::path::Path;
+use std::time::Instant;
+
+use common::output::{self, Format, Record};
+use common::solution::{self, Outcome};
+use days::Day;
+use rayon::prelude::*;
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse::<u8>()
+        .map_err(|_| format!("Invalid day \"{}\"", s))
+}
+
+/// Resolve day selectors such as "all", "5" or "3-7" into registered days, in order.
+fn select_days(selectors: &[String]) -> Result<Vec<&'static Day>, String> {
+    if selectors.is_empty() || selectors.iter().any(|s| s == "all") {
+        return Ok(days::DAYS.iter().collect());
+    }
+
+    let mut numbers = Vec::new();
+    for s in selectors {
+        if let Some((first, last)) = s.split_once('-') {
+            let first = parse_day(first)?;
+            let last = parse_day(last)?;
+            if first > last {
+                return Err(format!("Invalid day range \"{}\"", s));
+            }
+            numbers.extend(first..=last);
+        } else {
+            numbers.push(parse_day(s)?);
+        }
+    }
+
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    numbers
+        .into_iter()
+        .map(|n| days::find(n).ok_or(format!("Day {} is not implemented", n)))
+        .collect()
+}
+
+/// The workspace root, which inputs, answers and the download cache are relative to no matter
+/// where the runner is started from.
+pub(crate) fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc must live inside the workspace")
+}
+
+/// Path to the input for `day`, which is downloaded first if it is missing.
+pub(crate) fn input_path(day: &Day) -> Result<String, String> {
+    let path = workspace_root()
+        .join(format!("problem_{:02}/input.txt", day.number))
+        .display()
+        .to_string();
+    fetch::ensure_input(day.number, &path)
+        .map_err(|e| format!("{}: Input is missing and could not be fetched: {}", path, e))?;
+
+    Ok(path)
+}
+
+struct BenchOptions {
+    runs: usize,
+    json_path: Option<String>,
+    selectors: Vec<String>,
+}
+
+fn parse_bench_options(args: &[String]) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions {
+        runs: 10,
+        json_path: None,
+        selectors: Vec::new(),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let value = args.next().ok_or("Missing value for --runs")?;
+                options.runs = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid number of runs \"{}\"", value))?;
+            }
+            "--json" => {
+                let value = args.next().ok_or("Missing value for --json")?;
+                options.json_path = Some(value.clone());
+            }
+            _ => options.selectors.push(arg.clone()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} [--format json|csv|text] [--parallel] [--jobs N] [all | DAY | FIRST-LAST]...",
+        program
+    );
+    eprintln!(
+        "       {} bench [--runs N] [--json FILE] [all | DAY | FIRST-LAST]...",
+        program
+    );
+    eprintln!("       {} verify [all | DAY | FIRST-LAST]...", program);
+    std::process::exit(-1);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("bench") {
+        let parsed = parse_bench_options(&args[2..])
+            .and_then(|options| Ok((select_days(&options.selectors)?, options)));
+        let (selected, options) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("{}", e);
+                usage(&args[0]);
+            }
+        };
+
+        if !bench::run(&selected, options.runs, options.json_path.as_deref()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.get(1).map(String::as_str) == Some("verify") {
+        let selected = match select_days(&args[2..]) {
+            Ok(selected) => selected,
+            Err(e) => {
+                eprintln!("{}", e);
+                usage(&args[0]);
+            }
+        };
+
+        if !verify::run(&selected) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut selectors = args[1..].to_vec();
+    let parsed = output::take_format(&mut selectors).and_then(|format| {
+        let jobs = take_jobs(&mut selectors)?;
+        Ok((format, jobs, select_days(&selectors)?))
+    });
+    let (format, jobs, selected) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e);
+            usage(&args[0]);
+        }
+    };
+
+    let ok = match jobs {
+        None => run_serial(&selected, format),
+        Some(jobs) => run_parallel(&selected, format, jobs),
+    };
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+/// Remove `--parallel` and `--jobs N` from `args`. Returns the number of threads to run days on
+/// (0 for one per core), or None to run them one after the other.
+fn take_jobs(args: &mut Vec<String>) -> Result<Option<usize>, String> {
+    let mut jobs = None;
+    if let Some(i) = args.iter().position(|a| a == "--parallel") {
+        args.remove(i);
+        jobs = Some(0);
+    }
+    if let Some(i) = args.iter().position(|a| a == "--jobs") {
+        let value = args.get(i + 1).ok_or("Missing value for --jobs")?;
+        let n = value
+            .parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or(format!("Invalid number of jobs \"{}\"", value))?;
+        args.drain(i..i + 2);
+        jobs = Some(n);
+    }
+
+    Ok(jobs)
+}
+
+type DayResult = Result<(String, Outcome), String>;
+
+fn run_day(day: &Day, path: Result<String, String>) -> DayResult {
+    let path = path?;
+    let outcome = solution::run_file(day.solution, &path)?;
+
+    Ok((path, outcome))
+}
+
+/// Print the result for `day`, or collect it into `records` for the machine-readable formats.
+/// Returns false if the day failed.
+fn report(day: &Day, result: DayResult, format: Format, records: &mut Vec<Record>) -> bool {
+    match result {
+        Ok((_, outcome)) if format == Format::Text => {
+            for answer in outcome.answers {
+                println!("{}", answer);
+            }
+            true
+        }
+        Ok((path, outcome)) => {
+            records.extend(Record::from_outcome(day.number, &path, &outcome));
+            true
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+fn run_serial(selected: &[&Day], format: Format) -> bool {
+    let mut ok = true;
+    let mut records = Vec::new();
+    for day in selected {
+        if format == Format::Text {
+            println!("Day {:02}", day.number);
+        }
+        ok &= report(day, run_day(day, input_path(day)), format, &mut records);
+    }
+    output::print(format, &records);
+
+    ok
+}
+
+/// Run the days concurrently on a pool of `jobs` threads (0 for one per core), then print the
+/// results in day order followed by the wall-clock time for the whole run.
+fn run_parallel(selected: &[&Day], format: Format, jobs: usize) -> bool {
+    let pool = match rayon::ThreadPoolBuilder::new().num_threads(jobs).build() {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Unable to start thread pool: {}", e);
+            return false;
+        }
+    };
+
+    let start = Instant::now();
+    // Missing inputs are fetched up front, one at a time, to respect the rate limit
+    let paths: Vec<_> = selected.iter().map(|day| input_path(day)).collect();
+    let results: Vec<DayResult> = pool.install(|| {
+        selected
+            .par_iter()
+            .zip(paths)
+            .map(|(day, path)| run_day(day, path))
+            .collect()
+    });
+    let elapsed = start.elapsed();
+
+    let mut ok = true;
+    let mut records = Vec::new();
+    for (day, result) in selected.iter().zip(results) {
+        if format == Format::Text {
+            println!("Day {:02}", day.number);
+        }
+        ok &= report(day, result, format, &mut records);
+    }
+    output::print(format, &records);
+
+    if format == Format::Text {
+        println!("Wall-clock total: {:.1?}", elapsed);
+    } else {
+        eprintln!("Wall-clock total: {:.1?}", elapsed);
+    }
+
+    ok
+}
@@ -0,0 +1,170 @@
+use std::fmt::Write;
+
+use common::{solution, Answer};
+use serde::Deserialize;
+
+use crate::days::Day;
+
+/// Recorded answers for a day's `input.txt`, stored in `problem_NN/answers.toml`.
+#[derive(Deserialize)]
+struct Answers {
+    part_one: String,
+    part_two: String,
+}
+
+fn answers_path(day: &Day) -> String {
+    crate::workspace_root()
+        .join(format!("problem_{:02}/answers.toml", day.number))
+        .display()
+        .to_string()
+}
+
+fn load_answers(path: &str) -> Result<Option<Answers>, String> {
+    let data = match std::fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("{}: Unable to open answers file: {}", path, e)),
+    };
+
+    toml::from_str(&data)
+        .map(Some)
+        .map_err(|e| format!("{}: Invalid answers file: {}", path, e))
+}
+
+/// Describe how `actual` differs from `expected`, or return None if they match.
+/// Multi-line answers are compared line by line, marking only the lines that differ.
+fn compare(expected: &Answer, actual: &Answer) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let expected = expected.to_string();
+    let actual = actual.to_string();
+    let mut report = String::new();
+    if !expected.contains('\n') && !actual.contains('\n') {
+        writeln!(report, "    expected {}", expected).unwrap();
+        writeln!(report, "    actual   {}", actual).unwrap();
+        return Some(report);
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (Some(e), Some(a)) if e == a => writeln!(report, "      {}", e).unwrap(),
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(report, "    - {}", e).unwrap();
+                }
+                if let Some(a) = a {
+                    writeln!(report, "    + {}", a).unwrap();
+                }
+            }
+        }
+    }
+
+    Some(report)
+}
+
+/// Run each of `days` and compare the answers with the recorded ones.
+/// Returns false if any day failed or produced a different answer.
+pub fn run(days: &[&Day]) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+
+    for day in days {
+        let path = answers_path(day);
+        let expected = match load_answers(&path) {
+            Ok(Some(answers)) => answers,
+            Ok(None) => {
+                println!("Day {:02}: no recorded answers", day.number);
+                missing += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("Day {:02}: FAILED", day.number);
+                eprintln!("{}", e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let actual =
+            match crate::input_path(day).and_then(|path| solution::run_file(day.solution, &path)) {
+                Ok(outcome) => outcome.answers,
+                Err(e) => {
+                    println!("Day {:02}: FAILED", day.number);
+                    eprintln!("{}", e);
+                    failed += 1;
+                    continue;
+                }
+            };
+
+        let mismatches: Vec<_> = [
+            ("one", &expected.part_one, &actual[0]),
+            ("two", &expected.part_two, &actual[1]),
+        ]
+        .into_iter()
+        .filter_map(|(part, e, a)| {
+            let e: Answer = e.parse().unwrap();
+            compare(&e, a).map(|report| (part, report))
+        })
+        .collect();
+
+        if mismatches.is_empty() {
+            println!("Day {:02}: ok", day.number);
+            passed += 1;
+        } else {
+            println!("Day {:02}: FAILED", day.number);
+            for (part, report) in mismatches {
+                println!("  Part {} differs from {}", part, path);
+                print!("{}", report);
+            }
+            failed += 1;
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} without recorded answers",
+        passed, failed, missing
+    );
+
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(s: &str) -> Answer {
+        s.parse().unwrap()
+    }
+
+    fn grid(rows: &[&str]) -> Answer {
+        Answer::Grid(rows.iter().map(|r| r.to_string()).collect())
+    }
+
+    #[test]
+    fn compare_single_line() {
+        assert_eq!(compare(&answer("1121"), &Answer::from(1121_usize)), None);
+        assert_eq!(
+            compare(&answer("1121"), &Answer::from(1122_u32)).unwrap(),
+            "    expected 1121\n    actual   1122\n"
+        );
+    }
+
+    #[test]
+    fn compare_multi_line() {
+        assert_eq!(compare(&answer("#.\n.#\n"), &grid(&["#.", ".#"])), None);
+        assert_eq!(
+            compare(&answer("#.\n.#\n"), &grid(&["#.", "##"])).unwrap(),
+            "      #.\n    - .#\n    + ##\n"
+        );
+        assert_eq!(
+            compare(&answer("#.\n.#\n"), &grid(&["#."])).unwrap(),
+            "      #.\n    - .#\n"
+        );
+    }
+}
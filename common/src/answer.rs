@@ -0,0 +1,145 @@
+use std::convert::{Infallible, TryFrom};
+use std::fmt;
+use std::str::FromStr;
+
+/// A puzzle answer of any of the shapes the days produce.
+///
+/// Integers compare equal regardless of which variant holds them, so an answer computed as `u32`
+/// matches the same value computed as `usize` or read back from a file. Likewise a string equals a
+/// grid with the same text, since a one-row grid reads back as a string.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    String(String),
+    /// Rows of a rendered grid, e.g. letters drawn with `#` and `.`.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Answer::Integer(n) => Some(n as i128),
+            Answer::BigInteger(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::String(a), Answer::String(b)) => a == b,
+            (Answer::Grid(a), Answer::Grid(b)) => a == b,
+            (Answer::String(s), Answer::Grid(rows)) | (Answer::Grid(rows), Answer::String(s)) => {
+                *s == rows.join("\n")
+            }
+            _ => match (self.as_i128(), other.as_i128()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::String(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Read an answer back from its displayed form: integers become numbers, multi-line text a grid
+/// (ignoring a trailing newline) and anything else a string.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_suffix('\n').unwrap_or(s);
+        if let Ok(n) = s.parse::<i64>() {
+            Ok(Answer::Integer(n))
+        } else if let Ok(n) = s.parse::<i128>() {
+            Ok(Answer::BigInteger(n))
+        } else if s.contains('\n') {
+            Ok(Answer::Grid(s.lines().map(str::to_owned).collect()))
+        } else {
+            Ok(Answer::String(s.to_owned()))
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Integer(n),
+                        Err(_) => Answer::BigInteger(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_equality() {
+        assert_eq!(Answer::from(1714950_u32), Answer::from(1714950_usize));
+        assert_eq!(Answer::from(-3_isize), Answer::BigInteger(-3));
+        assert_ne!(Answer::from(5_u8), Answer::from("5"));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+
+        let row = Answer::Grid(vec!["#..#".to_owned()]);
+        assert_eq!(row, Answer::from("#..#"));
+        assert_eq!(Answer::from("#..#"), row);
+        assert_ne!(row, Answer::from("#..."));
+        assert_ne!(Answer::Grid(vec!["5".to_owned()]), Answer::from(5_u8));
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let grid = Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!("#.\n.#\n".parse::<Answer>().unwrap(), grid);
+        assert_eq!(
+            "3018019237563".parse::<Answer>().unwrap(),
+            Answer::from(3018019237563_i64)
+        );
+        assert_eq!(
+            "170141183460469231731687303715884105727"
+                .parse::<Answer>()
+                .unwrap(),
+            Answer::BigInteger(i128::MAX)
+        );
+        assert_eq!("abc".parse::<Answer>().unwrap(), Answer::from("abc"));
+
+        // A one-row grid reads back as a string but still matches the grid it came from
+        let row = Answer::Grid(vec!["#..#".to_owned()]);
+        assert_eq!(row.to_string().parse::<Answer>().unwrap(), row);
+    }
+}
@@ -1,8 +1,84 @@
 use num_traits::PrimInt;
 use std::marker::PhantomData;
 
+use crate::grid::Connectivity;
+
 pub mod index;
+pub mod text;
+pub mod tiled;
+pub mod transform;
+pub mod view;
+
+pub use text::Render;
+pub use tiled::Tiled;
+pub use view::View;
+
+// Offsets to the neighbors of a cell, in reading order
+const NEIGHBORS4: [(i8, i8); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBORS8: [(i8, i8); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// `p` moved by `(dx, dy)`, if that stays within the `width` by `height` rectangle starting at
+/// `(x0, y0)`.
+fn offset<U: PrimInt>(
+    (x, y): (U, U),
+    (dx, dy): (i8, i8),
+    (x0, y0): (U, U),
+    (width, height): (U, U),
+) -> Option<(U, U)> {
+    let x = U::from(x.to_i64()? + dx as i64)?;
+    let y = U::from(y.to_i64()? + dy as i64)?;
+    if x < x0 || x - x0 >= width || y < y0 || y - y0 >= height {
+        return None;
+    }
+
+    Some((x, y))
+}
+
+/// The horizontal and vertical neighbors of `p` that lie within a grid of size `dim`.
+pub fn neighbors4<U: PrimInt>(p: (U, U), dim: (U, U)) -> impl Iterator<Item = (U, U)> {
+    neighbors(p, dim, Connectivity::Four)
+}
+
+/// The horizontal, vertical and diagonal neighbors of `p` that lie within a grid of size `dim`.
+pub fn neighbors8<U: PrimInt>(p: (U, U), dim: (U, U)) -> impl Iterator<Item = (U, U)> {
+    neighbors(p, dim, Connectivity::Eight)
+}
+
+/// Either `neighbors4` or `neighbors8`, depending on `connectivity`.
+pub fn neighbors<U: PrimInt>(
+    p: (U, U),
+    dim: (U, U),
+    connectivity: Connectivity,
+) -> impl Iterator<Item = (U, U)> {
+    neighbors_within(p, (U::zero(), U::zero()), dim, connectivity)
+}
+
+/// Like `neighbors`, for a grid of size `dim` whose top left corner is at `origin`.
+pub fn neighbors_within<U: PrimInt>(
+    p: (U, U),
+    origin: (U, U),
+    dim: (U, U),
+    connectivity: Connectivity,
+) -> impl Iterator<Item = (U, U)> {
+    let offsets: &[(i8, i8)] = match connectivity {
+        Connectivity::Four => &NEIGHBORS4,
+        Connectivity::Eight => &NEIGHBORS8,
+    };
+    offsets
+        .iter()
+        .filter_map(move |&d| offset(p, d, origin, dim))
+}
 
+#[derive(Clone)]
 pub struct Array2D<'a, T, U> {
     data: Vec<T>,
     pub width: U,
@@ -20,12 +96,21 @@ impl<'a, T, U> Array2D<'a, T, U> {
         }
     }
 
+    /// Fill the array row by row from `input`.
+    ///
+    /// # Panics
+    /// If the number of items is not a multiple of `width`.
     pub fn from_iter<I>(input: I, width: U) -> Self
     where
         I: Iterator<Item = T>,
         U: PrimInt,
     {
         let data: Vec<T> = input.collect();
+        assert!(
+            data.len().is_multiple_of(width.to_usize().unwrap()),
+            "Expected a multiple of {} items",
+            width.to_usize().unwrap()
+        );
         let height = U::from(data.len() / width.to_usize().unwrap()).unwrap();
         Self {
             data,
@@ -53,6 +138,59 @@ impl<'a, T, U> Array2D<'a, T, U> {
         x < U::zero() || x >= self.width || y < U::zero() || y >= self.height
     }
 
+    /// All cells in reading order, along with their coordinates.
+    pub fn cells(&self) -> impl Iterator<Item = ((U, U), &T)> + '_
+    where
+        U: PrimInt,
+    {
+        let width = self.width.to_usize().unwrap();
+        self.data.iter().enumerate().map(move |(i, value)| {
+            let x = U::from(i % width).unwrap();
+            let y = U::from(i / width).unwrap();
+            ((x, y), value)
+        })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]>
+    where
+        U: PrimInt,
+    {
+        self.data.chunks(self.width.to_usize().unwrap().max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>>
+    where
+        U: PrimInt,
+    {
+        let width = self.width.to_usize().unwrap();
+        (0..width).map(move |x| self.data.iter().skip(x).step_by(width))
+    }
+
+    /// The in-bounds horizontal and vertical neighbors of `p`. Only coordinates are returned, so
+    /// the array can still be modified while iterating.
+    pub fn neighbors4(&self, p: (U, U)) -> impl Iterator<Item = (U, U)>
+    where
+        U: PrimInt,
+    {
+        neighbors4(p, (self.width, self.height))
+    }
+
+    /// Like `neighbors4`, but including diagonal neighbors.
+    pub fn neighbors8(&self, p: (U, U)) -> impl Iterator<Item = (U, U)>
+    where
+        U: PrimInt,
+    {
+        neighbors8(p, (self.width, self.height))
+    }
+
     /// # Safety
     /// No bounds checking will occur.
     pub unsafe fn get_unchecked(&self, (x, y): (U, U)) -> &T
@@ -78,14 +216,70 @@ impl<'a, T, U> Array2D<'a, T, U> {
 impl<'a, T, U> std::fmt::Debug for Array2D<'a, T, U>
 where
     T: std::fmt::Debug,
-    U: std::fmt::Debug + PrimInt + std::iter::Step,
+    U: std::fmt::Debug + PrimInt,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for r in U::zero()..self.height {
-            let start = r.to_usize().unwrap() * self.width.to_usize().unwrap();
+        for r in 0..self.height.to_usize().unwrap() {
+            let start = r * self.width.to_usize().unwrap();
             let end = start + self.width.to_usize().unwrap();
             writeln!(f, "{:?}", &self.data[start..end])?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Array2D<'static, u8, i16> {
+        Array2D::from_iter(1..=6, 3)
+    }
+
+    #[test]
+    fn cells_rows_columns() {
+        let a = sample();
+        let cells: Vec<_> = a.cells().map(|(p, &v)| (p, v)).collect();
+        assert_eq!(cells[4], ((1, 1), 5));
+        assert_eq!(a.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+
+        let columns: Vec<Vec<u8>> = a.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn neighbors_in_bounds() {
+        let mut a = sample();
+        assert_eq!(a.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(
+            a.neighbors4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1), (2, 1)]
+        );
+        assert_eq!(a.neighbors8((2, 0)).count(), 3);
+        assert_eq!(a.neighbors8((1, 0)).count(), 5);
+
+        // Neighbors do not borrow the array
+        for n in a.neighbors8((0, 0)) {
+            a[n] += 10;
+        }
+        assert_eq!(
+            a.values().copied().collect::<Vec<_>>(),
+            [1, 12, 3, 14, 15, 6]
+        );
+    }
+
+    #[test]
+    fn neighbors_unsigned() {
+        let a: Array2D<u8, usize> = Array2D::from_iter(0..4, 2);
+        assert_eq!(
+            a.neighbors8((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a multiple of 4 items")]
+    fn from_iter_ragged() {
+        Array2D::<u8, i16>::from_iter(0..6, 4);
+    }
+}
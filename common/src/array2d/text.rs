@@ -0,0 +1,114 @@
+use num_traits::PrimInt;
+use std::fmt;
+
+use super::Array2D;
+use crate::error::{Error, Result};
+use crate::parse;
+
+impl<'a, T, U> Array2D<'a, T, U>
+where
+    U: PrimInt,
+{
+    fn from_grid(source: &str, text: &str, (data, width): (Vec<T>, usize)) -> Result<Self> {
+        let height = data.len() / width;
+        match (U::from(width), U::from(height)) {
+            (Some(width), Some(height)) => Ok(Self::from_vec(data, (width, height))),
+            _ => Err(Error::locate(source, text, "Grid is too large")),
+        }
+    }
+
+    /// Render the cells row by row, converting each with `f`.
+    pub fn display_with<F>(&self, f: F) -> Render<'_, 'a, T, U, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Render { array: self, f }
+    }
+}
+
+impl<'a, U> Array2D<'a, u8, U>
+where
+    U: PrimInt,
+{
+    /// Parse a block of single digits, where `text` is a slice of `source`.
+    /// Rows of different lengths are reported as errors.
+    pub fn parse_digits(source: &str, text: &str) -> Result<Self> {
+        Self::from_grid(source, text, parse::digit_grid(source, text)?)
+    }
+}
+
+impl<'a, U> Array2D<'a, char, U>
+where
+    U: PrimInt,
+{
+    /// Parse a block of characters, where `text` is a slice of `source`.
+    /// Rows of different lengths are reported as errors.
+    pub fn parse_chars(source: &str, text: &str) -> Result<Self> {
+        Self::from_grid(source, text, parse::char_grid(source, text)?)
+    }
+}
+
+/// Displays an `Array2D` as lines of characters. Created by `Array2D::display_with`.
+pub struct Render<'r, 'a, T, U, F> {
+    array: &'r Array2D<'a, T, U>,
+    f: F,
+}
+
+impl<'r, 'a, T, U, F> fmt::Display for Render<'r, 'a, T, U, F>
+where
+    U: PrimInt,
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.array.rows() {
+            let line: String = row.iter().map(&self.f).collect();
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_digits() {
+        let a: Array2D<u8, i16> = Array2D::parse_digits("123\n456\n", "123\n456\n").unwrap();
+        assert_eq!((a.width, a.height), (3, 2));
+        assert_eq!(a[(2, 1)], 6);
+    }
+
+    #[test]
+    fn parse_chars() {
+        let a: Array2D<char, usize> = Array2D::parse_chars("#.\n.#\n", "#.\n.#\n").unwrap();
+        assert_eq!((a.width, a.height), (2, 2));
+        assert_eq!(a[(1, 1)], '#');
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let data = "123\n45\n";
+        let e = Array2D::<u8, i16>::parse_digits(data, data).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "45"));
+        assert_eq!(e.message, "Expected 3 characters per row");
+    }
+
+    #[test]
+    fn too_large() {
+        let data = "1".repeat(200);
+        let e = Array2D::<u8, i8>::parse_digits(&data, &data).err().unwrap();
+        assert_eq!(e.message, "Grid is too large");
+    }
+
+    #[test]
+    fn display_with() {
+        let data = "1090\n0001\n";
+        let a: Array2D<u8, i16> = Array2D::parse_digits(data, data).unwrap();
+        let rendered = a
+            .display_with(|&d| if d > 0 { '#' } else { '.' })
+            .to_string();
+        assert_eq!(rendered, "#.#.\n...#\n");
+    }
+}
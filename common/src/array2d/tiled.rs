@@ -0,0 +1,88 @@
+use num_traits::PrimInt;
+
+use super::Array2D;
+use crate::Grid;
+
+/// A virtual grid made of copies of a base `Array2D`, laid out side by side. Each value is
+/// computed on access from the base cell and the coordinates of the tile it falls in, so the full
+/// grid is never allocated. Created by `Array2D::tiled`.
+pub struct Tiled<'r, 'a, T, U, F> {
+    base: &'r Array2D<'a, T, U>,
+    transform: F,
+    pub width: U,
+    pub height: U,
+}
+
+impl<'a, T, U> Array2D<'a, T, U>
+where
+    U: PrimInt,
+{
+    /// Repeat the array `x_tiles` times horizontally and `y_tiles` times vertically, passing each
+    /// value through `transform` along with the coordinates of its tile.
+    pub fn tiled<F>(&self, (x_tiles, y_tiles): (U, U), transform: F) -> Tiled<'_, 'a, T, U, F>
+    where
+        F: Fn(&T, (U, U)) -> T,
+    {
+        Tiled {
+            base: self,
+            transform,
+            width: self.width * x_tiles,
+            height: self.height * y_tiles,
+        }
+    }
+}
+
+impl<'r, 'a, T, U, F> Grid for Tiled<'r, 'a, T, U, F>
+where
+    U: PrimInt,
+    F: Fn(&T, (U, U)) -> T,
+{
+    type Coord = U;
+    type Value = T;
+
+    fn dimensions(&self) -> (U, U) {
+        (self.width, self.height)
+    }
+
+    fn get(&self, p @ (x, y): (U, U)) -> Option<T> {
+        if !self.in_bounds(p) {
+            return None;
+        }
+
+        let (width, height) = (self.base.width, self.base.height);
+        let tile = (x / width, y / height);
+        let value = unsafe { self.base.get_unchecked((x % width, y % height)) };
+
+        Some((self.transform)(value, tile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get() {
+        // 1 2
+        // 3 4
+        let base: Array2D<u8, i16> = Array2D::from_iter(1..=4, 2);
+        let tiled = base.tiled((3, 2), |&v, (x, y)| v + 10 * x as u8 + 100 * y as u8);
+        assert_eq!((tiled.width, tiled.height), (6, 4));
+        assert_eq!(tiled.get((0, 0)), Some(1));
+        assert_eq!(tiled.get((3, 1)), Some(14));
+        assert_eq!(tiled.get((4, 2)), Some(121));
+        assert_eq!(tiled.get((6, 0)), None);
+        assert_eq!(tiled.get((0, -1)), None);
+    }
+
+    #[test]
+    fn neighbors_cross_tiles() {
+        let base: Array2D<u8, i16> = Array2D::from_iter(1..=4, 2);
+        let tiled = base.tiled((2, 2), |&v, _| v);
+        assert_eq!(
+            tiled.neighbors4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(tiled.neighbors8((3, 3)).count(), 3);
+    }
+}
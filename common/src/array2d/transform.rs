@@ -0,0 +1,131 @@
+use num_traits::PrimInt;
+
+use super::Array2D;
+
+impl<'a, T, U> Array2D<'a, T, U>
+where
+    U: PrimInt,
+{
+    /// A new array of the same size with `f` applied to every cell.
+    pub fn map<V>(&self, f: impl FnMut(&T) -> V) -> Array2D<'a, V, U> {
+        Array2D::from_vec(self.data.iter().map(f).collect(), (self.width, self.height))
+    }
+
+    /// Build an array of size `(width, height)` where each cell is copied from the cell of `self`
+    /// that `source` maps its coordinates to.
+    fn remap(
+        &self,
+        (width, height): (U, U),
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let old_width = self.width.to_usize().unwrap();
+        let new_width = width.to_usize().unwrap();
+        let new_height = height.to_usize().unwrap();
+
+        let mut data = Vec::with_capacity(new_width * new_height);
+        for y in 0..new_height {
+            for x in 0..new_width {
+                let (u, v) = source(x, y);
+                data.push(self.data[v * old_width + u].clone());
+            }
+        }
+
+        Self::from_vec(data, (width, height))
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap((self.height, self.width), |x, y| (y, x))
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height.to_usize().unwrap().saturating_sub(1);
+        self.remap((self.height, self.width), |x, y| (y, last_row - x))
+    }
+
+    /// Rotate a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.width.to_usize().unwrap().saturating_sub(1);
+        self.remap((self.height, self.width), |x, y| (last_column - y, x))
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.width.to_usize().unwrap().saturating_sub(1);
+        self.remap((self.width, self.height), |x, y| (last_column - x, y))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height.to_usize().unwrap().saturating_sub(1);
+        self.remap((self.width, self.height), |x, y| (x, last_row - y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 2 3
+    // 4 5 6
+    fn sample() -> Array2D<'static, u8, i16> {
+        Array2D::from_iter(1..=6, 3)
+    }
+
+    fn rows(a: &Array2D<u8, i16>) -> Vec<Vec<u8>> {
+        a.rows().map(|r| r.to_vec()).collect()
+    }
+
+    #[test]
+    fn map() {
+        let a = sample().map(|&v| v as u32 * 10);
+        assert_eq!((a.width, a.height), (3, 2));
+        assert_eq!(a[(2, 1)], 60);
+    }
+
+    #[test]
+    fn transpose() {
+        let a = sample().transpose();
+        assert_eq!((a.width, a.height), (2, 3));
+        assert_eq!(rows(&a), [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(rows(&sample().rotate_right()), [[4, 1], [5, 2], [6, 3]]);
+        assert_eq!(rows(&sample().rotate_left()), [[3, 6], [2, 5], [1, 4]]);
+
+        let a = sample();
+        let full_turn = a
+            .rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right();
+        assert_eq!(rows(&full_turn), rows(&a));
+    }
+
+    #[test]
+    fn flip() {
+        assert_eq!(rows(&sample().flip_horizontal()), [[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(rows(&sample().flip_vertical()), [[4, 5, 6], [1, 2, 3]]);
+    }
+}
@@ -0,0 +1,130 @@
+use num_traits::PrimInt;
+use std::ops::Index;
+
+use super::Array2D;
+
+/// A borrowed rectangular region of an `Array2D`. Coordinates are relative to the top left corner
+/// of the region.
+pub struct View<'v, T, U> {
+    data: &'v [T],
+    stride: usize,
+    pub width: U,
+    pub height: U,
+}
+
+impl<'a, T, U> Array2D<'a, T, U>
+where
+    U: PrimInt,
+{
+    /// The region of size `(width, height)` starting at `(x, y)`, or None if any part of it lies
+    /// outside the array.
+    pub fn view(&self, (x, y): (U, U), (width, height): (U, U)) -> Option<View<'_, T, U>> {
+        let zero = U::zero();
+        if x < zero || y < zero || width < zero || height < zero {
+            return None;
+        }
+        if x + width > self.width || y + height > self.height {
+            return None;
+        }
+
+        let stride = self.width.to_usize().unwrap();
+        let start = y.to_usize().unwrap() * stride + x.to_usize().unwrap();
+        Some(View {
+            data: &self.data[start.min(self.data.len())..],
+            stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'v, T, U> View<'v, T, U>
+where
+    U: PrimInt,
+{
+    pub fn get(&self, index: (U, U)) -> Option<&'v T> {
+        if self.out_of_bounds(index) {
+            return None;
+        }
+
+        let (x, y) = index;
+        Some(&self.data[y.to_usize().unwrap() * self.stride + x.to_usize().unwrap()])
+    }
+
+    fn out_of_bounds(&self, (x, y): (U, U)) -> bool {
+        x < U::zero() || x >= self.width || y < U::zero() || y >= self.height
+    }
+
+    /// Copy the region into an array of its own.
+    pub fn to_array2d(&self) -> Array2D<'v, T, U>
+    where
+        T: Clone,
+    {
+        let width = self.width.to_usize().unwrap();
+        let height = self.height.to_usize().unwrap();
+        let data = (0..height)
+            .flat_map(|y| &self.data[y * self.stride..y * self.stride + width])
+            .cloned()
+            .collect();
+
+        Array2D::from_vec(data, (self.width, self.height))
+    }
+}
+
+impl<'v, T, U> Index<(U, U)> for View<'v, T, U>
+where
+    U: PrimInt,
+{
+    type Output = T;
+
+    fn index(&self, index: (U, U)) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2 3
+    // 4 5 6 7
+    // 8 9 A B
+    fn sample() -> Array2D<'static, u8, i16> {
+        Array2D::from_iter(0..12, 4)
+    }
+
+    #[test]
+    fn get_and_index() {
+        let a = sample();
+        let v = a.view((1, 1), (2, 2)).unwrap();
+        assert_eq!((v.width, v.height), (2, 2));
+        assert_eq!(v[(0, 0)], 5);
+        assert_eq!(v[(1, 1)], 10);
+        assert_eq!(v.get((2, 0)), None);
+        assert_eq!(v.get((0, -1)), None);
+    }
+
+    #[test]
+    fn to_array2d() {
+        let a = sample();
+        let v = a.view((2, 0), (2, 3)).unwrap().to_array2d();
+        assert_eq!(v.rows().collect::<Vec<_>>(), [[2, 3], [6, 7], [10, 11]]);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let a = sample();
+        assert!(a.view((3, 0), (2, 1)).is_none());
+        assert!(a.view((0, 2), (1, 2)).is_none());
+        assert!(a.view((-1, 0), (1, 1)).is_none());
+        assert!(a.view((4, 3), (0, 0)).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn index_out_of_bounds() {
+        let a = sample();
+        let v = a.view((0, 0), (2, 2)).unwrap();
+        let _ = v[(0, 2)];
+    }
+}
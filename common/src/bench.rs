@@ -0,0 +1,96 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of repeated measurements of the same piece of work.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarize `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+
+        Self {
+            min: samples[0],
+            median,
+            mean,
+        }
+    }
+}
+
+/// Timings for the three stages of a solution, measured separately.
+#[derive(Clone, Copy, Debug)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+/// Run `f` `runs` times and summarize how long each call took.
+/// The result of the last call is returned so that it can be fed to the next stage.
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    assert!(runs > 0, "Need at least one run");
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let value = black_box(f());
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    (Stats::from_samples(&mut samples), result.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_odd() {
+        let mut samples: Vec<_> = [5, 1, 3]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn stats_even() {
+        let mut samples: Vec<_> = [4, 1, 2, 9]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn measure_returns_last_result() {
+        let mut calls = 0;
+        let (_, last) = measure(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(last, 3);
+    }
+}
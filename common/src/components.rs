@@ -0,0 +1,296 @@
+//! Connected regions of grid cells, separated by walls.
+
+use fnv::FnvHashSet;
+use num_traits::{NumCast, PrimInt, ToPrimitive};
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::{Array2D, Connectivity, Grid};
+
+/// Disjoint sets of the numbers `0..n`, merged by union by size with path halving.
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+
+        x
+    }
+
+    /// Merge the sets containing `a` and `b`. Returns false if they were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+
+        true
+    }
+
+    /// The number of elements in the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+/// Every cell reachable from `start` through adjacent cells without crossing a cell for which
+/// `is_wall` returns true, in the order they were reached. Empty if `start` is itself a wall or
+/// out of bounds.
+pub fn flood_fill<G>(
+    grid: &G,
+    start: (G::Coord, G::Coord),
+    connectivity: Connectivity,
+    is_wall: impl Fn(&G::Value) -> bool,
+) -> Vec<(G::Coord, G::Coord)>
+where
+    G: Grid,
+    G::Coord: Hash,
+{
+    let open = |p| grid.get(p).is_some_and(|v| !is_wall(&v));
+    if !open(start) {
+        return Vec::new();
+    }
+
+    let mut filled = vec![start];
+    let mut seen = FnvHashSet::default();
+    seen.insert(start);
+    let mut frontier = VecDeque::from([start]);
+    while let Some(p) = frontier.pop_front() {
+        for n in grid.neighbors(p, connectivity) {
+            if open(n) && seen.insert(n) {
+                filled.push(n);
+                frontier.push_back(n);
+            }
+        }
+    }
+
+    filled
+}
+
+/// The connected regions of a grid, as computed by `label_components`.
+pub struct Components<U> {
+    /// The grid coordinates of `labels[(0, 0)]`
+    pub origin: (U, U),
+    /// The region of each cell relative to `origin`, or None for walls
+    pub labels: Array2D<'static, Option<usize>, U>,
+    /// The number of cells in each region, indexed by label
+    pub sizes: Vec<usize>,
+}
+
+/// Label the regions of `grid` separated by cells for which `is_wall` returns true. Labels are
+/// numbered from 0 in reading order of each region's first cell.
+///
+/// The grid is scanned once, merging each open cell with its open neighbors that have already
+/// been visited, so this is much cheaper than a flood fill from every cell. The grid must be
+/// bounded.
+pub fn label_components<G>(
+    grid: &G,
+    connectivity: Connectivity,
+    is_wall: impl Fn(&G::Value) -> bool,
+) -> Components<G::Coord>
+where
+    G: Grid,
+{
+    let origin @ (x0, y0) = grid.origin();
+    let (width, height) = grid.dimensions();
+    let (w, h) = (width.to_usize().unwrap(), height.to_usize().unwrap());
+    let cells = w.checked_mul(h).expect("Grid too large to label");
+
+    let mut sets = UnionFind::new(cells);
+    let mut open = vec![false; cells];
+    for y in 0..h {
+        for x in 0..w {
+            let p = (
+                x0 + NumCast::from(x).unwrap(),
+                y0 + NumCast::from(y).unwrap(),
+            );
+            let i = y * w + x;
+            open[i] = grid.get(p).is_some_and(|v| !is_wall(&v));
+            if !open[i] {
+                continue;
+            }
+            if x > 0 && open[i - 1] {
+                sets.union(i, i - 1);
+            }
+            if y > 0 && open[i - w] {
+                sets.union(i, i - w);
+            }
+            if connectivity == Connectivity::Eight && y > 0 {
+                if x > 0 && open[i - w - 1] {
+                    sets.union(i, i - w - 1);
+                }
+                if x + 1 < w && open[i - w + 1] {
+                    sets.union(i, i - w + 1);
+                }
+            }
+        }
+    }
+
+    let mut root_labels = vec![None; cells];
+    let mut sizes = Vec::new();
+    let mut labels = Vec::with_capacity(cells);
+    for (i, &open) in open.iter().enumerate() {
+        if !open {
+            labels.push(None);
+            continue;
+        }
+
+        let root = sets.find(i);
+        let label = *root_labels[root].get_or_insert_with(|| {
+            sizes.push(sets.size(root));
+            sizes.len() - 1
+        });
+        labels.push(Some(label));
+    }
+
+    Components {
+        origin,
+        labels: Array2D::from_vec(labels, (width, height)),
+        sizes,
+    }
+}
+
+impl<U: PrimInt> Components<U> {
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    /// The label of the region containing `p`, or None if `p` is a wall or out of bounds.
+    pub fn label(&self, (x, y): (U, U)) -> Option<usize> {
+        let (x0, y0) = self.origin;
+        if x < x0 || y < y0 {
+            return None;
+        }
+        self.labels.get((x - x0, y - y0)).copied().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SparseGrid;
+
+    // Two regions separated by 9s, the larger of which wraps around a wall
+    const MAP: &str = "\
+1191
+1191
+9911
+1111
+";
+
+    fn map() -> Array2D<'static, u8, i32> {
+        Array2D::parse_digits(MAP, MAP).unwrap()
+    }
+
+    #[test]
+    fn union_find() {
+        let mut sets = UnionFind::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(!sets.union(1, 0));
+        assert!(sets.union(4, 1));
+        assert_eq!(sets.find(0), sets.find(3));
+        assert_eq!(sets.size(4), 4);
+        assert_eq!(sets.size(2), 1);
+    }
+
+    #[test]
+    fn fill() {
+        let map = map();
+        let mut filled = flood_fill(&map, (3, 0), Connectivity::Four, |&h| h == 9);
+        filled.sort_unstable();
+        assert_eq!(filled.len(), 8);
+        assert!(filled.contains(&(2, 2)));
+        assert!(flood_fill(&map, (2, 0), Connectivity::Four, |&h| h == 9).is_empty());
+        assert_eq!(
+            flood_fill(&map, (0, 0), Connectivity::Four, |&h| h == 9).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn components() {
+        let map = map();
+        let components = label_components(&map, Connectivity::Four, |&h| h == 9);
+        assert_eq!(components.sizes, [4, 8]);
+        assert_eq!(components.label((1, 1)), Some(0));
+        assert_eq!(components.label((3, 0)), Some(1));
+        assert_eq!(components.label((0, 3)), Some(1));
+        assert_eq!(components.label((2, 0)), None);
+        assert_eq!(components.label((4, 0)), None);
+    }
+
+    #[test]
+    fn components_merge_late() {
+        // The two arms of the U are only joined on the last row
+        let data = "1.1\n1.1\n111\n";
+        let grid: Array2D<char, i32> = Array2D::parse_chars(data, data).unwrap();
+        let components = label_components(&grid, Connectivity::Four, |&c| c == '.');
+        assert_eq!(components.sizes, [7]);
+        assert_eq!(components.label((2, 0)), Some(0));
+    }
+
+    #[test]
+    fn components_with_origin() {
+        let mut grid = SparseGrid::new();
+        for p in [(-3, -2), (-2, -2), (-1, 0), (-1, 1)] {
+            grid.insert(p, ());
+        }
+        let components = label_components(&grid, Connectivity::Four, Option::is_none);
+        assert_eq!(components.origin, (-3, -2));
+        assert_eq!(components.sizes, [2, 2]);
+        assert_eq!(components.label((-3, -2)), Some(0));
+        assert_eq!(components.label((-1, 1)), Some(1));
+        assert_eq!(components.label((-1, -2)), None);
+        assert_eq!(components.label((-4, 0)), None);
+        assert_eq!(
+            flood_fill(&grid, (-1, 0), Connectivity::Four, Option::is_none).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn diagonal_connectivity() {
+        let data = "1.1\n.1.\n1..\n";
+        let grid: Array2D<char, i32> = Array2D::parse_chars(data, data).unwrap();
+        let wall = |&c: &char| c == '.';
+
+        assert_eq!(
+            label_components(&grid, Connectivity::Four, wall).sizes,
+            [1, 1, 1, 1]
+        );
+        assert_eq!(
+            label_components(&grid, Connectivity::Eight, wall).sizes,
+            [4]
+        );
+        assert_eq!(
+            flood_fill(&grid, (2, 0), Connectivity::Eight, wall).len(),
+            4
+        );
+        assert_eq!(flood_fill(&grid, (2, 0), Connectivity::Four, wall).len(), 1);
+    }
+}
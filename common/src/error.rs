@@ -0,0 +1,118 @@
+use std::fmt;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A parse error pointing at the offending text in a puzzle input.
+///
+/// Lines and columns are 1-based. A line of 0 means that the position is unknown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub message: String,
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl Error {
+    pub fn new(
+        message: impl Into<String>,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            line,
+            column,
+            text: text.into(),
+        }
+    }
+
+    /// Create an error for `fragment`, which must be a subslice of `source`.
+    /// The line and column are derived from where the fragment starts.
+    pub fn locate(source: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = source.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+        if offset > source.len()
+            || fragment.len() > source.len() - offset
+            || !source.is_char_boundary(offset)
+        {
+            return Self::new(message, 0, 0, fragment);
+        }
+
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::new(message, line, column, fragment)
+    }
+
+    /// Create an error pointing just past the end of `source`, e.g. for missing sections.
+    pub fn at_end(source: &str, message: impl Into<String>) -> Self {
+        Self::locate(source, &source[source.len()..], message)
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        if self.line > 0 {
+            write!(f, "{}:{}:", self.line, self.column)?;
+        }
+        write!(f, " {}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_fragment() {
+        let source = "1,2\n3,x4\n";
+        let fragment = &source[6..8];
+        let e = Error::locate(source, fragment, "Unable to parse integer");
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.text, "x4");
+        assert_eq!(
+            e.to_string(),
+            "<input>:2:3: Unable to parse integer: \"x4\""
+        );
+    }
+
+    #[test]
+    fn locate_foreign_fragment() {
+        let source = String::from("abc");
+        let e = Error::locate(&source, "def", "Unexpected text");
+        assert_eq!(e.line, 0);
+        assert_eq!(
+            e.with_file("input.txt").to_string(),
+            "input.txt: Unexpected text: \"def\""
+        );
+    }
+
+    #[test]
+    fn locate_end() {
+        let e = Error::at_end("abc\nde", "Missing section");
+        assert_eq!((e.line, e.column), (2, 3));
+    }
+}
@@ -0,0 +1,184 @@
+use num_traits::{PrimInt, Zero};
+
+use crate::array2d::{self, Array2D};
+
+/// Which cells count as adjacent: only horizontal and vertical neighbors, or diagonal ones too.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+/// A bounded rectangle of cells addressed by `(x, y)` coordinates. Lets algorithms such as
+/// searches and fills work the same way on dense arrays, sparse grids and virtual views.
+pub trait Grid {
+    type Coord: PrimInt;
+    type Value;
+
+    /// The top left corner. `(0, 0)` unless the grid extends to negative coordinates.
+    fn origin(&self) -> (Self::Coord, Self::Coord) {
+        (Self::Coord::zero(), Self::Coord::zero())
+    }
+
+    /// The width and height. Cells lie in `x0..x0 + width` and `y0..y0 + height`, where
+    /// `(x0, y0)` is the origin.
+    fn dimensions(&self) -> (Self::Coord, Self::Coord);
+
+    /// The value at `p`, or None if `p` is out of bounds.
+    fn get(&self, p: (Self::Coord, Self::Coord)) -> Option<Self::Value>;
+
+    fn in_bounds(&self, (x, y): (Self::Coord, Self::Coord)) -> bool {
+        let (x0, y0) = self.origin();
+        let (width, height) = self.dimensions();
+        x >= x0 && x - x0 < width && y >= y0 && y - y0 < height
+    }
+
+    /// The in-bounds horizontal and vertical neighbors of `p`.
+    fn neighbors4(
+        &self,
+        p: (Self::Coord, Self::Coord),
+    ) -> impl Iterator<Item = (Self::Coord, Self::Coord)> {
+        self.neighbors(p, Connectivity::Four)
+    }
+
+    /// Like `neighbors4`, but including diagonal neighbors.
+    fn neighbors8(
+        &self,
+        p: (Self::Coord, Self::Coord),
+    ) -> impl Iterator<Item = (Self::Coord, Self::Coord)> {
+        self.neighbors(p, Connectivity::Eight)
+    }
+
+    /// Either `neighbors4` or `neighbors8`, depending on `connectivity`.
+    fn neighbors(
+        &self,
+        p: (Self::Coord, Self::Coord),
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Self::Coord, Self::Coord)> {
+        array2d::neighbors_within(p, self.origin(), self.dimensions(), connectivity)
+    }
+}
+
+/// A grid whose cells can be changed. Virtual views only implement `Grid`.
+pub trait GridMut: Grid {
+    /// Store `value` at `p`.
+    ///
+    /// # Panics
+    /// If `p` is out of bounds of a grid that cannot grow.
+    fn set(&mut self, p: (Self::Coord, Self::Coord), value: Self::Value);
+}
+
+impl<'a, T, U> Grid for Array2D<'a, T, U>
+where
+    T: Clone,
+    U: PrimInt,
+{
+    type Coord = U;
+    type Value = T;
+
+    fn dimensions(&self) -> (U, U) {
+        (self.width, self.height)
+    }
+
+    fn get(&self, p: (U, U)) -> Option<T> {
+        Array2D::get(self, p).cloned()
+    }
+}
+
+impl<'a, T, U> GridMut for Array2D<'a, T, U>
+where
+    T: Clone,
+    U: PrimInt,
+{
+    fn set(&mut self, p: (U, U), value: T) {
+        self[p] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::hash::Hash;
+
+    /// Count the cells reachable from `start` through cells equal to its value.
+    fn region_size<G>(grid: &G, start: (G::Coord, G::Coord)) -> usize
+    where
+        G: Grid,
+        G::Coord: Hash,
+        G::Value: PartialEq,
+    {
+        let value = grid.get(start);
+        let mut seen = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(p) = stack.pop() {
+            if grid.get(p) == value && seen.insert(p) {
+                stack.extend(grid.neighbors4(p));
+            }
+        }
+
+        seen.len()
+    }
+
+    #[test]
+    fn array2d() {
+        let data = "1122\n1222\n3332\n";
+        let mut a: Array2D<u8, i16> = Array2D::parse_digits(data, data).unwrap();
+        assert_eq!(a.dimensions(), (4, 3));
+        assert_eq!(region_size(&a, (0, 0)), 3);
+        assert_eq!(region_size(&a, (3, 0)), 6);
+
+        a.set((1, 0), 2);
+        assert_eq!(region_size(&a, (0, 0)), 2);
+        assert_eq!(Grid::get(&a, (4, 0)), None);
+    }
+
+    /// An array shifted so that its top left corner is at `(-2, -1)`
+    struct Shifted(Array2D<'static, u8, i16>);
+
+    impl Grid for Shifted {
+        type Coord = i16;
+        type Value = u8;
+
+        fn origin(&self) -> (i16, i16) {
+            (-2, -1)
+        }
+
+        fn dimensions(&self) -> (i16, i16) {
+            self.0.dimensions()
+        }
+
+        fn get(&self, (x, y): (i16, i16)) -> Option<u8> {
+            if !self.in_bounds((x, y)) {
+                return None;
+            }
+            self.0.get((x + 2, y + 1)).copied()
+        }
+    }
+
+    #[test]
+    fn origin() {
+        let data = "1122\n1222\n3332\n";
+        let shifted = Shifted(Array2D::parse_digits(data, data).unwrap());
+        assert!(shifted.in_bounds((-2, -1)));
+        assert!(shifted.in_bounds((1, 1)));
+        assert!(!shifted.in_bounds((2, 1)));
+        assert!(!shifted.in_bounds((-3, 0)));
+        assert_eq!(
+            shifted.neighbors4((-2, -1)).collect::<Vec<_>>(),
+            [(-1, -1), (-2, 0)]
+        );
+        assert_eq!(region_size(&shifted, (-2, -1)), 3);
+        assert_eq!(region_size(&shifted, (1, -1)), 6);
+    }
+
+    #[test]
+    fn tiled() {
+        let data = "11\n21\n";
+        let a: Array2D<u8, i16> = Array2D::parse_digits(data, data).unwrap();
+        let tiled = a.tiled((2, 2), |&v, _| v);
+        assert_eq!(tiled.dimensions(), (4, 4));
+        assert_eq!(region_size(&tiled, (0, 0)), 12);
+        assert_eq!(region_size(&tiled, (2, 1)), 1);
+    }
+}
@@ -0,0 +1,67 @@
+//! Cleaning up puzzle inputs before they are parsed.
+
+use crate::error::{Error, Result};
+
+/// Normalize `data` so that parsers only ever see `\n`-terminated lines of printable ASCII.
+///
+/// A leading byte order mark is dropped, CRLF line endings become LF, trailing whitespace is
+/// removed from every line and trailing blank lines are collapsed into a single final newline.
+/// Any other control or non-ASCII character is an error.
+pub fn normalize(data: &str) -> Result<String> {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+
+    let mut normalized = String::with_capacity(data.len() + 1);
+    for line in data.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let line = line.trim_end_matches([' ', '\t']);
+
+        if let Some((i, c)) = line
+            .char_indices()
+            .find(|&(_, c)| c != ' ' && !c.is_ascii_graphic())
+        {
+            return Err(Error::locate(
+                data,
+                &line[i..i + c.len_utf8()],
+                "Unexpected character",
+            ));
+        }
+
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+
+    let end = normalized.trim_end_matches('\n').len();
+    normalized.truncate(end);
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings_and_whitespace() {
+        assert_eq!(
+            normalize("\u{feff}1 2 \r\n\r\n 3\t\r\n\r\n\n").unwrap(),
+            "1 2\n\n 3\n"
+        );
+        assert_eq!(normalize("abc").unwrap(), "abc\n");
+        assert_eq!(normalize("\n\n").unwrap(), "");
+    }
+
+    #[test]
+    fn unexpected_characters() {
+        let e = normalize("123\r\n4\u{7}6\r\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "\u{7}"));
+
+        let e = normalize("12\r3\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+
+        let e = normalize("ab\nc\u{e9}\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "\u{e9}"));
+    }
+}
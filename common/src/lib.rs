@@ -1,5 +1,19 @@
-#![feature(step_trait)]
-
+pub mod answer;
 pub mod array2d;
+pub mod bench;
+pub mod components;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod sparse;
 
+pub use answer::Answer;
 pub use array2d::Array2D;
+pub use error::Error;
+pub use grid::{Connectivity, Grid, GridMut};
+pub use solution::{Runnable, Solution};
+pub use sparse::SparseGrid;
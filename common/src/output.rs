@@ -0,0 +1,164 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::solution::Outcome;
+
+/// How answers are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Bare answers, one per line.
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Invalid format \"{}\", expected json, csv or text",
+                s
+            )),
+        }
+    }
+}
+
+/// One labeled answer, as emitted by the machine-readable formats.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u128,
+    pub time_ns: u128,
+}
+
+impl Record {
+    /// Label both answers of `outcome`, which was produced by `day` from `input`.
+    pub fn from_outcome(day: u8, input: &str, outcome: &Outcome) -> [Record; 2] {
+        let record = |i: usize| Record {
+            day,
+            input: input.to_owned(),
+            part: i as u8 + 1,
+            answer: outcome.answers[i].to_string(),
+            parse_ns: outcome.parse_time.as_nanos(),
+            time_ns: outcome.part_times[i].as_nanos(),
+        };
+
+        [record(0), record(1)]
+    }
+}
+
+/// Remove a `--format FORMAT` option from `args`, returning the format (text by default).
+pub fn take_format(args: &mut Vec<String>) -> Result<Format, String> {
+    match args.iter().position(|a| a == "--format") {
+        Some(i) => {
+            let value = args.get(i + 1).ok_or("Missing value for --format")?;
+            let format = value.parse()?;
+            args.drain(i..i + 2);
+            Ok(format)
+        }
+        None => Ok(Format::Text),
+    }
+}
+
+/// Print `records` in a machine-readable `format`. Text output is up to the caller.
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).unwrap()
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Render `records` as CSV with a header row. Multi-line answers are quoted.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,input,part,answer,parse_ns,time_ns\n");
+    for r in records {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            r.day,
+            csv_field(&r.input),
+            r.part,
+            csv_field(&r.answer),
+            r.parse_ns,
+            r.time_ns
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_record() -> Record {
+        Record {
+            day: 13,
+            input: "input.txt".to_owned(),
+            part: 2,
+            answer: "#.\n.#".to_owned(),
+            parse_ns: 10,
+            time_ns: 20,
+        }
+    }
+
+    #[test]
+    fn format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn take_format_option() {
+        let mut args: Vec<String> = ["a", "--format", "csv", "b"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(take_format(&mut args), Ok(Format::Csv));
+        assert_eq!(args, ["a", "b"]);
+        assert_eq!(take_format(&mut args), Ok(Format::Text));
+
+        let mut args = vec!["--format".to_owned()];
+        assert!(take_format(&mut args).is_err());
+    }
+
+    #[test]
+    fn json_multi_line() {
+        let json = to_json(&[grid_record()]);
+        assert!(json.contains("\"answer\": \"#.\\n.#\""), "{}", json);
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let mut record = grid_record();
+        record.input = "a,\"b\".txt".to_owned();
+        assert_eq!(
+            to_csv(&[record]),
+            "day,input,part,answer,parse_ns,time_ns\n13,\"a,\"\"b\"\".txt\",2,\"#.\n.#\",10,20\n"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Helpers for splitting and converting puzzle inputs.
+//!
+//! Functions that can fail take the whole input as `source` next to the `text` being parsed, which
+//! must be a slice of `source`, so that errors point at the right line and column.
+
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// The non-empty lines of `text`.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n').filter(|s| !s.is_empty())
+}
+
+/// The blocks of `text` separated by blank lines.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n").filter(|s| !s.trim().is_empty())
+}
+
+/// Parse `text` as a single integer.
+pub fn integer<T: FromStr>(source: &str, text: &str) -> Result<T> {
+    text.parse::<T>()
+        .map_err(|_| Error::locate(source, text, "Unable to parse integer"))
+}
+
+/// Parse a list of integers separated by `separator`. Items are trimmed and empty ones skipped,
+/// so that space-aligned columns such as `" 8  2 23"` and a trailing newline parse as expected.
+pub fn integer_list<T: FromStr>(source: &str, text: &str, separator: char) -> Result<Vec<T>> {
+    text.split(separator)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| integer(source, s))
+        .collect()
+}
+
+/// Parse each non-empty line of `text` as an integer.
+pub fn integer_lines<T: FromStr>(source: &str, text: &str) -> Result<Vec<T>> {
+    lines(text).map(|s| integer(source, s)).collect()
+}
+
+/// Split `line` around the first `separator`, e.g. `"AB -> C"` around `" -> "`.
+pub fn key_value<'a>(source: &str, line: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    line.split_once(separator)
+        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+        .ok_or_else(|| Error::locate(source, line, format!("Expected \"KEY{}VALUE\"", separator)))
+}
+
+/// Split every non-empty line of `text` into a key and a value, see `key_value`.
+pub fn rules<'a>(source: &str, text: &'a str, separator: &str) -> Result<Vec<(&'a str, &'a str)>> {
+    lines(text)
+        .map(|line| key_value(source, line, separator))
+        .collect()
+}
+
+/// Convert a rectangular block of characters with `f`, returning the cells row by row along
+/// with the width. `expected` describes the accepted characters in errors.
+pub fn grid_with<T>(
+    source: &str,
+    text: &str,
+    expected: &str,
+    f: impl Fn(char) -> Option<T>,
+) -> Result<(Vec<T>, usize)> {
+    let mut rows = lines(text).peekable();
+
+    // Peek first element to ascertain width
+    let first = rows
+        .peek()
+        .ok_or_else(|| Error::locate(source, &text[text.len()..], "Input is empty"))?;
+    let width = first.chars().count();
+
+    let mut cells = Vec::new();
+    for row in rows {
+        if row.chars().count() != width {
+            return Err(Error::locate(
+                source,
+                row,
+                format!("Expected {} characters per row", width),
+            ));
+        }
+        for (i, c) in row.char_indices() {
+            let cell = f(c).ok_or_else(|| {
+                Error::locate(
+                    source,
+                    &row[i..i + c.len_utf8()],
+                    format!("Expected {}", expected),
+                )
+            })?;
+            cells.push(cell);
+        }
+    }
+
+    Ok((cells, width))
+}
+
+/// A rectangular block of characters, returned row by row along with the width.
+pub fn char_grid(source: &str, text: &str) -> Result<(Vec<char>, usize)> {
+    grid_with(source, text, "character", Some)
+}
+
+/// A rectangular block of decimal digits, returned row by row along with the width.
+pub fn digit_grid(source: &str, text: &str) -> Result<(Vec<u8>, usize)> {
+    grid_with(source, text, "digit", |c| c.to_digit(10).map(|d| d as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_lines_and_sections() {
+        let data = "a\nb\n\nc\n\n\nd\n";
+        assert_eq!(lines(data).collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+        assert_eq!(sections(data).collect::<Vec<_>>(), ["a\nb", "c", "\nd\n"]);
+    }
+
+    #[test]
+    fn integers() {
+        let data = "3,4,x\n 8  2 23\n";
+        let (first, second) = data.split_once('\n').unwrap();
+        assert_eq!(integer_list::<u8>(data, second, ' '), Ok(vec![8, 2, 23]));
+
+        let e = integer_list::<u8>(data, first, ',').unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "x"));
+
+        let e = integer_lines::<u8>(data, data).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+
+    #[test]
+    fn key_values() {
+        let data = "CH -> B\nHH - N\n";
+        let e = rules(data, data, " -> ").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "HH - N"));
+        assert_eq!(rules(data, &data[..8], " -> "), Ok(vec![("CH", "B")]));
+    }
+
+    #[test]
+    fn grids() {
+        let data = "header\n\n123\n456\n";
+        let grid = &data[8..];
+        assert_eq!(digit_grid(data, grid), Ok((vec![1, 2, 3, 4, 5, 6], 3)));
+        assert_eq!(char_grid(data, "ab\ncd").unwrap().1, 2);
+
+        let e = digit_grid(data, &data[..11]).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "h"));
+
+        let data = "123\n45\n";
+        let e = digit_grid(data, data).unwrap_err();
+        assert_eq!(
+            (e.line, e.message.as_str()),
+            (2, "Expected 3 characters per row")
+        );
+
+        let e = digit_grid(data, &data[..0]).unwrap_err();
+        assert_eq!(e.message, "Input is empty");
+    }
+}
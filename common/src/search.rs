@@ -0,0 +1,217 @@
+//! Shortest path searches over implicit graphs. Nodes can be any hashable value; the edges are
+//! produced on demand by a successor function, so grids, cave systems and game states can all be
+//! searched without building the graph up front.
+
+use fnv::FnvHashMap;
+use num_traits::Zero;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+/// A node that has been reached, along with the cheapest known way of getting there.
+struct Visit<N, C> {
+    node: N,
+    cost: C,
+    estimate: C,
+    parent: Option<usize>,
+}
+
+/// Follow the parent links from `last` back to the start.
+fn path<N: Clone, C>(visits: &[Visit<N, C>], last: usize) -> Vec<N> {
+    let mut path = Vec::new();
+    let mut current = Some(last);
+    while let Some(i) = current {
+        path.push(visits[i].node.clone());
+        current = visits[i].parent;
+    }
+    path.reverse();
+
+    path
+}
+
+/// Breadth-first search from `start` until a node satisfying `is_goal` is found. Every edge
+/// counts as one step. Returns the number of steps and the path, including both ends.
+pub fn bfs<N, S, I>(
+    start: N,
+    mut successors: S,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = FnvHashMap::default();
+    seen.insert(start.clone(), 0);
+    let mut visits = vec![Visit {
+        node: start,
+        cost: 0,
+        estimate: 0,
+        parent: None,
+    }];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&visits[i].node) {
+            return Some((visits[i].cost, path(&visits, i)));
+        }
+
+        for next in successors(&visits[i].node) {
+            if let Entry::Vacant(e) = seen.entry(next) {
+                let j = visits.len();
+                visits.push(Visit {
+                    node: e.key().clone(),
+                    cost: visits[i].cost + 1,
+                    estimate: 0,
+                    parent: Some(i),
+                });
+                e.insert(j);
+                queue.push_back(j);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from `start` until a node satisfying `is_goal` is found. `successors`
+/// yields each neighbor with the cost of the edge to it, which must not be negative. Returns the
+/// total cost and the path, including both ends.
+pub fn dijkstra<N, C, S, I>(
+    start: N,
+    successors: S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    a_star(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `start` until a node satisfying `is_goal` is found. Like `dijkstra`, but nodes
+/// are explored in order of cost plus `heuristic`, which must never overestimate the remaining
+/// cost for the result to be the cheapest path.
+pub fn a_star<N, C, S, I>(
+    start: N,
+    mut successors: S,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut seen = FnvHashMap::default();
+    seen.insert(start.clone(), 0);
+    let estimate = heuristic(&start);
+    let mut visits = vec![Visit {
+        node: start,
+        cost: C::zero(),
+        estimate,
+        parent: None,
+    }];
+    let mut queue = BinaryHeap::from([Reverse((estimate, C::zero(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        // Skip entries made stale by a cheaper path to the same node
+        if cost > visits[i].cost {
+            continue;
+        }
+        if is_goal(&visits[i].node) {
+            return Some((cost, path(&visits, i)));
+        }
+
+        for (next, step) in successors(&visits[i].node) {
+            let next_cost = cost + step;
+            let j = match seen.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if next_cost >= visits[j].cost {
+                        continue;
+                    }
+                    visits[j].cost = next_cost;
+                    visits[j].parent = Some(i);
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let j = visits.len();
+                    let estimate = heuristic(e.key());
+                    visits.push(Visit {
+                        node: e.key().clone(),
+                        cost: next_cost,
+                        estimate,
+                        parent: Some(i),
+                    });
+                    e.insert(j);
+                    j
+                }
+            };
+            queue.push(Reverse((next_cost + visits[j].estimate, next_cost, j)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A -1-> B -1-> D
+    // A -5-> C -1-> D
+    // B -1-> C      E (unreachable)
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'A' => vec![('B', 1), ('C', 5)],
+            'B' => vec![('C', 1), ('D', 4)],
+            'C' => vec![('D', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_fewest_steps() {
+        let successors = |n: &char| edges(n).into_iter().map(|(n, _)| n);
+        assert_eq!(
+            bfs('A', successors, |&n| n == 'D'),
+            Some((2, vec!['A', 'B', 'D']))
+        );
+        assert_eq!(bfs('A', successors, |&n| n == 'A'), Some((0, vec!['A'])));
+        assert_eq!(bfs('A', successors, |&n| n == 'E'), None);
+    }
+
+    #[test]
+    fn dijkstra_cheapest() {
+        assert_eq!(
+            dijkstra('A', edges, |&n| n == 'D'),
+            Some((3, vec!['A', 'B', 'C', 'D']))
+        );
+        assert_eq!(dijkstra('A', edges, |&n| n == 'E'), None);
+    }
+
+    #[test]
+    fn a_star_grid() {
+        // Walk around a wall on an open 5x5 grid
+        let wall = [(2, 0), (2, 1), (2, 2), (2, 3)];
+        let successors = |&p: &(i32, i32)| {
+            crate::array2d::neighbors4(p, (5, 5))
+                .filter(|n| !wall.contains(n))
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (4 - x).abs() + y.abs();
+
+        let (cost, path) = a_star((0, 0), successors, heuristic, |&p| p == (4, 0)).unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 0)));
+        assert!(path.contains(&(2, 4)));
+    }
+}
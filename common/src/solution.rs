@@ -0,0 +1,174 @@
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::bench::{self, Timings};
+use crate::error::{Error, Result};
+use crate::input;
+use crate::output::{self, Format, Record};
+
+/// A single day's puzzle, split into input parsing and the two parts.
+///
+/// Both parts receive the same parsed input so that they can be run (and timed) independently.
+pub trait Solution {
+    type Input;
+    type AnswerOne: Into<Answer>;
+    type AnswerTwo: Into<Answer>;
+
+    fn parse(&self, data: &str) -> Result<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne;
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo;
+}
+
+/// The answers for one input, with how long parsing and each part took.
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub answers: [Answer; 2],
+    pub parse_time: Duration,
+    pub part_times: [Duration; 2],
+}
+
+/// Type-erased view of a `Solution`, used to keep solutions for different days in the same registry.
+pub trait Runnable: Sync {
+    /// Normalize `data` with `input::normalize`, then parse it and solve both parts.
+    fn run(&self, data: &str) -> Result<Outcome>;
+
+    /// Time parsing and both parts separately, repeating each stage `runs` times.
+    fn bench(&self, data: &str, runs: usize) -> Result<Timings>;
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Sync,
+{
+    fn run(&self, data: &str) -> Result<Outcome> {
+        let data = input::normalize(data)?;
+
+        let start = Instant::now();
+        let input = self.parse(&data)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer_one = self.part_one(&input).into();
+        let time_one = start.elapsed();
+
+        let start = Instant::now();
+        let answer_two = self.part_two(&input).into();
+        let time_two = start.elapsed();
+
+        Ok(Outcome {
+            answers: [answer_one, answer_two],
+            parse_time,
+            part_times: [time_one, time_two],
+        })
+    }
+
+    fn bench(&self, data: &str, runs: usize) -> Result<Timings> {
+        // Parse once up front so that bad input is reported rather than timed
+        let data = input::normalize(data)?;
+        self.parse(&data)?;
+
+        let (parse, input) = bench::measure(runs, || self.parse(&data).unwrap());
+        let (part_one, _) = bench::measure(runs, || self.part_one(&input));
+        let (part_two, _) = bench::measure(runs, || self.part_two(&input));
+
+        Ok(Timings {
+            parse,
+            part_one,
+            part_two,
+        })
+    }
+}
+
+/// Path that stands for standard input.
+pub const STDIN: &str = "-";
+
+pub fn display_name(path: &str) -> &str {
+    if path == STDIN {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// Read the input at `path`, or from standard input if the path is `-`.
+pub fn read_input(path: &str) -> std::result::Result<String, String> {
+    let result = if path == STDIN {
+        let mut data = String::new();
+        std::io::stdin().read_to_string(&mut data).map(|_| data)
+    } else {
+        std::fs::read_to_string(path)
+    };
+
+    result.map_err(|e| format!("{}: Unable to open input file: {}", display_name(path), e))
+}
+
+/// Read and solve the input at `path`, attaching the path to any parse error.
+pub fn run_file(solution: &dyn Runnable, path: &str) -> std::result::Result<Outcome, String> {
+    let data = read_input(path)?;
+
+    solution
+        .run(&data)
+        .map_err(|e: Error| e.with_file(display_name(path)).to_string())
+}
+
+/// Read the input at `path` and benchmark the solution on it.
+pub fn bench_file(
+    solution: &dyn Runnable,
+    path: &str,
+    runs: usize,
+) -> std::result::Result<Timings, String> {
+    let data = read_input(path)?;
+
+    solution
+        .bench(&data, runs)
+        .map_err(|e: Error| e.with_file(display_name(path)).to_string())
+}
+
+/// Entry point shared by the per-day binaries.
+///
+/// Each argument is an input file, or `-` for standard input. With several inputs, the text
+/// answers for each are printed under its name.
+pub fn main(day: u8, solution: &impl Runnable) {
+    let mut args: Vec<String> = std::env::args().collect();
+    let format = output::take_format(&mut args);
+    let format = match format {
+        Ok(format) if args.len() >= 2 => format,
+        result => {
+            if let Err(e) = result {
+                eprintln!("{}", e);
+            }
+            eprintln!(
+                "Usage: {} [--format json|csv|text] problem_input... (- for stdin)",
+                args[0]
+            );
+            std::process::exit(-1);
+        }
+    };
+
+    let paths = &args[1..];
+    let mut failed = false;
+    let mut records = Vec::new();
+    for path in paths {
+        match run_file(solution, path) {
+            Ok(outcome) if format == Format::Text => {
+                if paths.len() > 1 {
+                    println!("{}:", display_name(path));
+                }
+                for answer in outcome.answers {
+                    println!("{}", answer);
+                }
+            }
+            Ok(outcome) => records.extend(Record::from_outcome(day, display_name(path), &outcome)),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+    output::print(format, &records);
+
+    if failed {
+        std::process::exit(1);
+    }
+}
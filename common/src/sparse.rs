@@ -0,0 +1,332 @@
+use fnv::FnvHashMap;
+use num_traits::PrimInt;
+use std::hash::Hash;
+
+use crate::{Array2D, Grid, GridMut};
+
+/// Values at scattered coordinates, for grids that are mostly empty or have no fixed size.
+/// The bounding box of the occupied cells is kept up to date as values are added and removed.
+#[derive(Clone)]
+pub struct SparseGrid<T, U = i32> {
+    cells: FnvHashMap<(U, U), T>,
+    bounds: Option<((U, U), (U, U))>,
+}
+
+impl<T, U> Default for SparseGrid<T, U> {
+    fn default() -> Self {
+        Self {
+            cells: FnvHashMap::default(),
+            bounds: None,
+        }
+    }
+}
+
+/// Coordinates of a `width` by `height` rectangle starting at `origin`, in reading order.
+fn region_points<U: PrimInt>(
+    (x0, y0): (U, U),
+    (width, height): (U, U),
+) -> impl Iterator<Item = (U, U)> {
+    let width = width.to_usize().unwrap_or(0);
+    let height = height.to_usize().unwrap_or(0);
+    (0..height).flat_map(move |y| {
+        (0..width).map(move |x| (x0 + U::from(x).unwrap(), y0 + U::from(y).unwrap()))
+    })
+}
+
+impl<T, U> SparseGrid<T, U>
+where
+    U: PrimInt + Hash,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy the cells of `array` that differ from `empty`, placing its top left corner at
+    /// `origin`. The inverse of `to_array2d`.
+    pub fn from_array2d(array: &Array2D<T, U>, (x0, y0): (U, U), empty: &T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut grid = Self::new();
+        for ((x, y), value) in array.cells() {
+            if value != empty {
+                grid.insert((x0 + x, y0 + y), value.clone());
+            }
+        }
+
+        grid
+    }
+
+    /// The number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, p: (U, U)) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn contains(&self, p: (U, U)) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Store `value` at `p`, returning the value that was there before.
+    pub fn insert(&mut self, p: (U, U), value: T) -> Option<T> {
+        self.include(p);
+        self.cells.insert(p, value)
+    }
+
+    /// The value at `p`, which is set to the default first if the cell is empty. Useful for
+    /// counting, e.g. `*grid.get_or_insert_default(p) += 1`.
+    pub fn get_or_insert_default(&mut self, p: (U, U)) -> &mut T
+    where
+        T: Default,
+    {
+        self.include(p);
+        self.cells.entry(p).or_default()
+    }
+
+    pub fn remove(&mut self, p: (U, U)) -> Option<T> {
+        let value = self.cells.remove(&p);
+        if value.is_some() && self.on_edge(p) {
+            self.recompute_bounds();
+        }
+
+        value
+    }
+
+    /// Keep only the cells for which `f` returns true.
+    pub fn retain(&mut self, mut f: impl FnMut((U, U), &mut T) -> bool) {
+        let before = self.cells.len();
+        self.cells.retain(|&p, value| f(p, value));
+        if self.cells.len() != before {
+            self.recompute_bounds();
+        }
+    }
+
+    /// The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((U, U), &T)> {
+        self.cells.iter().map(|(&p, value)| (p, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = (U, U)> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// The top left and bottom right corners of the occupied cells, inclusive, or None if the
+    /// grid is empty.
+    pub fn bounds(&self) -> Option<((U, U), (U, U))> {
+        self.bounds
+    }
+
+    /// The dense equivalent of the `width` by `height` rectangle starting at `origin`, with
+    /// `empty` in the unoccupied cells.
+    pub fn region(
+        &self,
+        origin: (U, U),
+        (width, height): (U, U),
+        empty: T,
+    ) -> Array2D<'static, T, U>
+    where
+        T: Clone,
+    {
+        let data = region_points(origin, (width, height))
+            .map(|p| self.get(p).unwrap_or(&empty).clone())
+            .collect();
+
+        Array2D::from_vec(data, (width, height))
+    }
+
+    /// The dense equivalent of the bounding box, with `empty` in the unoccupied cells, along with
+    /// the coordinates of its top left corner.
+    pub fn to_array2d(&self, empty: T) -> ((U, U), Array2D<'static, T, U>)
+    where
+        T: Clone,
+    {
+        let (origin, size) = self.bounding_region();
+        (origin, self.region(origin, size, empty))
+    }
+
+    /// Render the bounding box as lines of text, converting each cell with `f`.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> Vec<String> {
+        let (origin, size) = self.bounding_region();
+        self.render_region(origin, size, f)
+    }
+
+    /// Render the `width` by `height` rectangle starting at `origin` as lines of text.
+    pub fn render_region(
+        &self,
+        origin: (U, U),
+        size: (U, U),
+        f: impl Fn(Option<&T>) -> char,
+    ) -> Vec<String> {
+        let width = size.0.to_usize().unwrap_or(0);
+        let points: Vec<_> = region_points(origin, size).collect();
+
+        points
+            .chunks(width.max(1))
+            .map(|row| row.iter().map(|&p| f(self.get(p))).collect())
+            .collect()
+    }
+
+    fn bounding_region(&self) -> ((U, U), (U, U)) {
+        match self.bounds {
+            Some((min @ (x0, y0), (x1, y1))) => (min, (x1 - x0 + U::one(), y1 - y0 + U::one())),
+            None => ((U::zero(), U::zero()), (U::zero(), U::zero())),
+        }
+    }
+
+    fn include(&mut self, (x, y): (U, U)) {
+        self.bounds = Some(match self.bounds {
+            Some(((x0, y0), (x1, y1))) => ((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y))),
+            None => ((x, y), (x, y)),
+        });
+    }
+
+    fn on_edge(&self, (x, y): (U, U)) -> bool {
+        match self.bounds {
+            Some(((x0, y0), (x1, y1))) => x == x0 || x == x1 || y == y0 || y == y1,
+            None => false,
+        }
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = None;
+        let points: Vec<_> = self.cells.keys().copied().collect();
+        for p in points {
+            self.include(p);
+        }
+    }
+}
+
+/// As a `Grid`, a sparse grid covers its bounding box, with None in the unoccupied cells.
+impl<T, U> Grid for SparseGrid<T, U>
+where
+    T: Clone,
+    U: PrimInt + Hash,
+{
+    type Coord = U;
+    type Value = Option<T>;
+
+    fn origin(&self) -> (U, U) {
+        self.bounding_region().0
+    }
+
+    fn dimensions(&self) -> (U, U) {
+        self.bounding_region().1
+    }
+
+    fn get(&self, p: (U, U)) -> Option<Option<T>> {
+        if !self.in_bounds(p) {
+            return None;
+        }
+
+        Some(SparseGrid::get(self, p).cloned())
+    }
+}
+
+impl<T, U> GridMut for SparseGrid<T, U>
+where
+    T: Clone,
+    U: PrimInt + Hash,
+{
+    /// Storing a value outside the bounding box grows it instead of panicking.
+    fn set(&mut self, p: (U, U), value: Option<T>) {
+        match value {
+            Some(value) => self.insert(p, value),
+            None => self.remove(p),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SparseGrid<u8> {
+        let mut grid = SparseGrid::new();
+        grid.insert((2, 1), 1);
+        grid.insert((-1, 3), 2);
+        grid.insert((0, 0), 3);
+        grid
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = sample();
+        assert_eq!(grid.bounds(), Some(((-1, 0), (2, 3))));
+
+        // Removing an inner point keeps the box, removing an edge point shrinks it
+        grid.insert((1, 1), 4);
+        grid.remove((1, 1));
+        assert_eq!(grid.bounds(), Some(((-1, 0), (2, 3))));
+        grid.remove((-1, 3));
+        assert_eq!(grid.bounds(), Some(((0, 0), (2, 1))));
+
+        grid.retain(|(x, _), _| x > 0);
+        assert_eq!(grid.bounds(), Some(((2, 1), (2, 1))));
+        grid.retain(|_, _| false);
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn count() {
+        let mut grid: SparseGrid<u8> = SparseGrid::new();
+        for p in [(0, 0), (1, 0), (0, 0), (0, 0)] {
+            *grid.get_or_insert_default(p) += 1;
+        }
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get((0, 0)), Some(&3));
+        assert_eq!(grid.bounds(), Some(((0, 0), (1, 0))));
+    }
+
+    #[test]
+    fn array2d_round_trip() {
+        let grid = sample();
+        let (origin, array) = grid.to_array2d(0);
+        assert_eq!(origin, (-1, 0));
+        assert_eq!((array.width, array.height), (4, 4));
+        assert_eq!(array[(1, 0)], 3);
+        assert_eq!(array[(3, 1)], 1);
+
+        let copy = SparseGrid::from_array2d(&array, origin, &0);
+        assert_eq!(copy.len(), 3);
+        assert_eq!(copy.get((-1, 3)), Some(&2));
+        assert_eq!(copy.bounds(), grid.bounds());
+    }
+
+    #[test]
+    fn render() {
+        let grid = sample();
+        let symbol = |v: Option<&u8>| v.map_or('.', |&v| (b'0' + v) as char);
+        assert_eq!(grid.render(symbol), [".3..", "...1", "....", "2..."]);
+        assert_eq!(grid.render_region((0, 0), (3, 2), symbol), ["3..", "..1"]);
+        assert!(SparseGrid::<u8>::new().render(symbol).is_empty());
+    }
+
+    #[test]
+    fn grid() {
+        let mut grid = sample();
+        assert_eq!(grid.origin(), (-1, 0));
+        assert_eq!(grid.dimensions(), (4, 4));
+        assert_eq!(Grid::get(&grid, (2, 1)), Some(Some(1)));
+        assert_eq!(Grid::get(&grid, (-1, 3)), Some(Some(2)));
+        assert_eq!(Grid::get(&grid, (2, 2)), Some(None));
+        assert_eq!(Grid::get(&grid, (-2, 0)), None);
+        assert_eq!(grid.neighbors4((-1, 3)).count(), 2);
+
+        grid.set((2, 1), None);
+        assert!(!grid.contains((2, 1)));
+        grid.set((-3, 5), Some(4));
+        assert_eq!(grid.origin(), (-3, 0));
+        assert_eq!(grid.dimensions(), (4, 6));
+    }
+}
@@ -0,0 +1,77 @@
+use common::error::Result;
+use common::parse;
+use common::Solution;
+
+pub fn parse_measurements(data: &str) -> Result<Vec<u16>> {
+    parse::integer_lines(data, data)
+}
+
+pub fn solve_one(measurements: &[u16]) -> usize {
+    let mut result = 0;
+    let mut prev = u16::MAX;
+    for elem in measurements {
+        if *elem > prev {
+            result += 1;
+        }
+        prev = *elem;
+    }
+
+    result
+}
+
+pub fn solve_two(measurements: &[u16]) -> usize {
+    let mut result = 0;
+    let mut prev = u16::MAX;
+    for window in measurements.windows(3) {
+        let sum = window[0] + window[1] + window[2];
+        if sum > prev {
+            result += 1;
+        }
+        prev = sum;
+    }
+
+    result
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<u16>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_measurements(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_measurements(EXAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_measurements(EXAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    fn invalid_measurement() {
+        let e = parse_measurements("199\n2O0\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "2O0"));
+    }
+}
@@ -0,0 +1,130 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use std::ops::Add;
+
+#[derive(Default)]
+struct PositionOne {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Default)]
+struct PositionTwo {
+    x: u32,
+    y: u32,
+    aim: u32,
+}
+
+pub enum Direction {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+impl Add<&Direction> for PositionOne {
+    type Output = Self;
+    fn add(mut self, other: &Direction) -> Self {
+        use Direction::*;
+        match other {
+            Forward(d) => self.x += d,
+            Up(d) => self.y -= d,
+            Down(d) => self.y += d,
+        }
+        self
+    }
+}
+
+impl Add<&Direction> for PositionTwo {
+    type Output = Self;
+    fn add(mut self, other: &Direction) -> Self {
+        use Direction::*;
+        match other {
+            Forward(d) => {
+                self.x += d;
+                self.y += self.aim * d;
+            }
+            Up(d) => self.aim -= d,
+            Down(d) => self.aim += d,
+        }
+        self
+    }
+}
+
+pub fn parse_commands(data: &str) -> Result<Vec<Direction>> {
+    parse::lines(data)
+        .map(|s| {
+            let invalid = || Error::locate(data, s, "Invalid command");
+            let (command, dist_str) = s.split_once(' ').ok_or_else(invalid)?;
+            if dist_str.is_empty() || !dist_str.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            let dist = parse::integer::<u32>(data, dist_str)?;
+            use Direction::*;
+            match command {
+                "forward" => Ok(Forward(dist)),
+                "up" => Ok(Up(dist)),
+                "down" => Ok(Down(dist)),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+pub fn solve_one(commands: &[Direction]) -> u32 {
+    let pos = commands
+        .iter()
+        .fold(PositionOne::default(), |acc, dir| acc + dir);
+    pos.x * pos.y
+}
+
+pub fn solve_two(commands: &[Direction]) -> u32 {
+    let pos = commands
+        .iter()
+        .fold(PositionTwo::default(), |acc, dir| acc + dir);
+    pos.y * pos.x
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<Direction>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_commands(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_commands(EXAMPLE).unwrap()), 150);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_commands(EXAMPLE).unwrap()), 900);
+    }
+
+    #[test]
+    fn invalid_command() {
+        let e = parse_commands("forward 5\nbackward 2\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "backward 2"));
+    }
+}
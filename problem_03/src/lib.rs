@@ -0,0 +1,136 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+
+pub fn solve_one(input: &[u16], width: usize) -> u32 {
+    // Count bits per position
+    let mut counts = vec![0_u16; width];
+    for r in input {
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count += (r & (1 << i) != 0) as u16;
+        }
+    }
+
+    // Compile most common bits to gamma
+    let predicate = |x| x as usize * 2 > input.len();
+    let mut gamma = 0_u32;
+    for (i, count) in counts.iter().enumerate() {
+        let bit_value = predicate(*count) as u32;
+        gamma |= bit_value << i;
+    }
+
+    // Invert gamma to get least common bits
+    let invert = |x| {
+        let mut mask = 0_u32;
+        for i in 0..width {
+            mask |= 1 << i;
+        }
+        x ^ mask
+    };
+    let epsilon = invert(gamma);
+
+    gamma * epsilon
+}
+
+fn solve_two_impl(input: &[u16], width: usize, predicate: impl Fn(usize, usize) -> bool) -> u16 {
+    let mut results = std::collections::VecDeque::with_capacity(input.len());
+    for &r in input {
+        results.push_back(r);
+    }
+
+    // Iteratively refine result set using predicate
+    let mut i = width as isize - 1;
+    while results.len() > 1 && i >= 0 {
+        let mut count = 0;
+        for q in &results {
+            count += (q & (1 << i) != 0) as usize;
+        }
+
+        // Retain only if the ith bit matches the condition
+        let condition = predicate(count, results.len());
+        results.retain(|&x| (x & (1 << i) != 0) == condition);
+
+        i -= 1;
+    }
+
+    *results
+        .front()
+        .expect("No element satisfying predicate could be found")
+}
+
+pub fn solve_two(input: &[u16], width: usize) -> u32 {
+    let o_2 = solve_two_impl(input, width, |a, b| 2 * a >= b) as u32;
+    let co_2 = solve_two_impl(input, width, |a, b| 2 * a < b) as u32;
+
+    o_2 * co_2
+}
+
+pub fn parse_report(data: &str) -> Result<(Vec<u16>, usize)> {
+    let mut rows = parse::lines(data).peekable();
+
+    // Peek first element to ascertain width
+    let first = rows
+        .peek()
+        .ok_or_else(|| Error::at_end(data, "Input is empty"))?;
+    let width = first.len();
+
+    let entries = rows
+        .map(|s| {
+            if s.len() != width {
+                return Err(Error::locate(data, s, format!("Expected {} bits", width)));
+            }
+            u16::from_str_radix(s, 2)
+                .map_err(|_| Error::locate(data, s, "Unable to parse binary integer"))
+        })
+        .collect::<Result<Vec<u16>>>()?;
+
+    Ok((entries, width))
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = (Vec<u16>, usize);
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_report(data)
+    }
+
+    fn part_one(&self, (entries, width): &Self::Input) -> Self::AnswerOne {
+        solve_one(entries, *width)
+    }
+
+    fn part_two(&self, (entries, width): &Self::Input) -> Self::AnswerTwo {
+        solve_two(entries, *width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let (entries, width) = parse_report(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&entries, width), 198);
+    }
+
+    #[test]
+    fn example_two() {
+        let (entries, width) = parse_report(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&entries, width), 230);
+    }
+
+    #[test]
+    fn invalid_report() {
+        let e = parse_report("00100\n1110\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "1110"));
+
+        let e = parse_report("00100\n11120\n").err().unwrap();
+        assert_eq!(e.message, "Unable to parse binary integer");
+    }
+}
@@ -0,0 +1,240 @@
+use arrayvec::ArrayVec;
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use fnv::FnvHashMap;
+
+#[derive(Clone)]
+struct Square {
+    data: u8,
+}
+
+impl Square {
+    pub fn new(number: u8) -> Self {
+        Self {
+            data: number & 0x7F,
+        }
+    }
+
+    pub fn mark(&mut self) {
+        self.data |= 0x80;
+    }
+
+    pub fn number(&self) -> u8 {
+        self.data & 0x7F
+    }
+
+    pub fn is_marked(&self) -> bool {
+        (self.data & 0x80) != 0
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct BingoBoard {
+    rows: ArrayVec<ArrayVec<Square, 5>, 5>,
+    numbers: FnvHashMap<u8, usize>,
+}
+
+impl BingoBoard {
+    pub fn new() -> Self {
+        Self {
+            rows: ArrayVec::new(),
+            numbers: FnvHashMap::default(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: ArrayVec<u8, 5>) {
+        self.rows
+            .push(row.iter().map(|&n| Square::new(n)).collect());
+        let index = self.rows.len() - 1;
+        for &n in &row {
+            self.numbers.insert(n, index);
+        }
+    }
+
+    fn mark(&mut self, row_index: usize, number: u8) -> bool {
+        let row = &mut self.rows[row_index];
+
+        let col_index = row
+            .iter_mut()
+            .position(|x| x.number() == number)
+            .expect("Corrupt BingoBoard encountered");
+        let square = &mut row[col_index];
+        square.mark();
+
+        // Row check
+        if row.iter().all(|x| x.is_marked()) {
+            return true;
+        }
+
+        // Column check
+        assert_eq!(self.rows.len(), 5);
+        if self.rows.iter().all(|r| r[col_index].is_marked()) {
+            return true;
+        }
+
+        false
+    }
+
+    pub fn draw(&mut self, number: u8) -> bool {
+        if let Some(&index) = self.numbers.get(&number) {
+            return self.mark(index, number);
+        }
+
+        false
+    }
+}
+
+pub type BingoMap = FnvHashMap<usize, BingoBoard>;
+
+pub fn parse_draws(data: &str) -> Result<Vec<u8>> {
+    parse::integer_list(data, data, ',')
+}
+
+pub fn parse_boards(data: &str) -> Result<BingoMap> {
+    let rows: Vec<&str> = parse::lines(data).collect();
+    let mut i = 0;
+    rows.chunks(5)
+        .map(|chunk| {
+            if chunk.len() < 5 {
+                return Err(Error::locate(data, chunk[0], "Incomplete board"));
+            }
+
+            let mut board = BingoBoard::new();
+            for row in chunk {
+                let mut numbers = ArrayVec::new();
+                for n in parse::integer_list(data, row, ' ')? {
+                    numbers
+                        .try_push(n)
+                        .map_err(|_| Error::locate(data, row, "Too many numbers in row"))?;
+                }
+                if !numbers.is_full() {
+                    return Err(Error::locate(data, row, "Too few numbers in row"));
+                }
+                board.add_row(numbers);
+            }
+            i += 1;
+            Ok((i, board))
+        })
+        .collect()
+}
+
+fn do_draw(draw: u8, boards: &mut BingoMap) -> Option<usize> {
+    for (&i, v) in boards {
+        if v.draw(draw) {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn play(draws: &[u8], boards: &mut BingoMap) -> Option<(usize, u8)> {
+    for &d in draws {
+        if let Some(winning_index) = do_draw(d, boards) {
+            return Some((winning_index, d));
+        }
+    }
+
+    None
+}
+
+fn count_score(boards: &BingoMap, index: usize, draw: u8) -> u32 {
+    let winner = &boards[&index];
+    let sum_of_unmarked = winner
+        .rows
+        .iter()
+        .flat_map(|x| x.iter())
+        .filter(|&b| !b.is_marked())
+        .fold(0_u32, |acc, x| acc + x.number() as u32);
+
+    sum_of_unmarked * draw as u32
+}
+
+pub fn solve_one(draws: &[u8], boards: &mut BingoMap) -> u32 {
+    let (winning_index, winning_draw) = play(draws, boards).expect("No winner found");
+    count_score(boards, winning_index, winning_draw)
+}
+
+pub fn solve_two(draws: &[u8], mut boards: BingoMap) -> u32 {
+    while boards.len() > 1 {
+        let (w, _) = play(draws, &mut boards).expect("No winner found");
+        boards.remove(&w);
+    }
+
+    let (winning_index, winning_draw) = play(draws, &mut boards).expect("No winner found");
+    count_score(&boards, winning_index, winning_draw)
+}
+
+pub fn parse_input(data: &str) -> Result<(Vec<u8>, BingoMap)> {
+    // Split input into draws and boards
+    let split_index = data
+        .find('\n')
+        .ok_or_else(|| Error::at_end(data, "Missing boards"))?;
+    let (draws_only, boards_only) = data.split_at(split_index);
+
+    // Boards start on the line ending the draws, so positions stay relative to the whole input
+    let draws = parse_draws(draws_only)?;
+    let boards = parse_boards(boards_only)?;
+    if boards.is_empty() {
+        return Err(Error::at_end(data, "No boards found"));
+    }
+
+    Ok((draws, boards))
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = (Vec<u8>, BingoMap);
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, (draws, boards): &Self::Input) -> Self::AnswerOne {
+        solve_one(draws, &mut boards.clone())
+    }
+
+    fn part_two(&self, (draws, boards): &Self::Input) -> Self::AnswerTwo {
+        solve_two(draws, boards.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let (draws, mut boards) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&draws, &mut boards), 4512);
+    }
+
+    #[test]
+    fn example_two() {
+        let (draws, boards) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&draws, boards), 1924);
+    }
+
+    #[test]
+    fn invalid_board() {
+        let mut data = EXAMPLE.replace(" 6 10  3 18  5", " 6 10  3 18");
+        let e = parse_input(&data).err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (6, 1, "Too few numbers in row")
+        );
+
+        data = EXAMPLE.replace("21  9 14", "21  x 14");
+        let e = parse_input(&data).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (5, 5, "x"));
+
+        let e = parse_input("1,2\n").err().unwrap();
+        assert_eq!((e.line, e.message.as_str()), (2, "No boards found"));
+    }
+}
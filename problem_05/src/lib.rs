@@ -0,0 +1,111 @@
+pub mod geometry;
+pub mod plot;
+
+use crate::geometry::*;
+use crate::plot::*;
+
+// Traits
+use std::hash::Hash;
+use std::str::FromStr;
+
+// Other
+use common::error::Result;
+use common::parse;
+use common::{Solution, SparseGrid};
+
+pub fn parse_lines<T>(input: &str) -> Result<Vec<Line<T>>>
+where
+    T: FromStr,
+{
+    let point = |s: &str| -> Result<Vec2<T>> {
+        let (x, y) = parse::key_value(input, s, ",")?;
+        Ok(Vec2::<T> {
+            x: parse::integer(input, x)?,
+            y: parse::integer(input, y)?,
+        })
+    };
+
+    parse::rules(input, input, " -> ")?
+        .into_iter()
+        .map(|(start, end)| Ok((point(start)?, point(end)?)))
+        .collect()
+}
+
+pub fn solve<'a, T>(input: impl Iterator<Item = &'a Line<T>>) -> usize
+where
+    T: 'static + PlottingNumber + Hash,
+{
+    let mut counts: SparseGrid<u8, T> = SparseGrid::new();
+
+    for line in input {
+        for p in plot_line(line) {
+            *counts.get_or_insert_default((p.x, p.y)) += 1;
+        }
+    }
+
+    counts.values().filter(|&&v| v >= 2).count()
+}
+
+pub fn solve_one<T>(lines: &[Line<T>]) -> usize
+where
+    T: 'static + PlottingNumber + Hash,
+{
+    solve(lines.iter().filter(|(a, b)| a.x == b.x || a.y == b.y))
+}
+
+pub fn solve_two<T>(lines: &[Line<T>]) -> usize
+where
+    T: 'static + PlottingNumber + Hash,
+{
+    solve(lines.iter())
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<Line<i32>>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_lines::<i32>(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let lines: Vec<Line<i32>> = parse_lines(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&lines), 5);
+    }
+
+    #[test]
+    fn example_two() {
+        let lines: Vec<Line<i32>> = parse_lines(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&lines), 12);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let e = parse_lines::<i32>("0,9 -> 5,9\n8,0 => 0,8\n")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "8,0 => 0,8"));
+
+        let e = parse_lines::<i8>("0,9 -> 500,9\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 8, "500"));
+    }
+}
@@ -1,12 +1,11 @@
 use crate::geometry::*;
 
 use num_traits::{FromPrimitive, PrimInt, Signed};
-use std::iter::Step;
 
-pub trait PlottingNumber: 'static + FromPrimitive + PrimInt + Signed + Step {}
+pub trait PlottingNumber: 'static + FromPrimitive + PrimInt + Signed {}
 
 // Default impl
-impl<T> PlottingNumber for T where T: 'static + FromPrimitive + PrimInt + Signed + Step {}
+impl<T> PlottingNumber for T where T: 'static + FromPrimitive + PrimInt + Signed {}
 
 /*
     Bresenham's line algorithm as described at https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm
@@ -49,8 +48,10 @@ where
 
     let mut d = (two * dy) - dx;
     let mut y = a.y;
+    let x0 = a.x;
 
-    Box::new((a.x..(b.x + T::one())).map(move |x| {
+    Box::new((0..=dx.to_usize().unwrap()).map(move |i| {
+        let x = x0 + T::from_usize(i).unwrap();
         let result = Vec2::<T> { x, y };
 
         if d > T::zero() {
@@ -77,8 +78,10 @@ where
 
     let mut d: T = (two * dx) - dy;
     let mut x = a.x;
+    let y0 = a.y;
 
-    Box::new((a.y..(b.y + T::one())).map(move |y| {
+    Box::new((0..=dy.to_usize().unwrap()).map(move |i| {
+        let y = y0 + T::from_usize(i).unwrap();
         let result = Vec2::<T> { x, y };
 
         if d > T::zero() {
@@ -0,0 +1,92 @@
+use common::error::Result;
+use common::parse;
+use common::Solution;
+
+pub type ResultCache = fnv::FnvHashMap<(u16, u8), usize>;
+
+fn spawn(days: u16, until_spawn: u8, cache: &mut ResultCache) -> usize {
+    if let Some(&cached) = cache.get(&(days, until_spawn)) {
+        return cached;
+    }
+
+    let result = match (days, until_spawn) {
+        (0, _) => 1,
+        (_, 0) => spawn(days - 1, 6, cache) + spawn(days - 1, 8, cache),
+        _ => spawn(days - 1, until_spawn - 1, cache),
+    };
+
+    cache.insert((days, until_spawn), result);
+
+    result
+}
+
+pub fn simulate(input: &[u8], days: u16, cache: &mut ResultCache) -> usize {
+    input.iter().map(|&x| spawn(days, x, cache)).sum()
+}
+
+pub fn parse_fish(data: &str) -> Result<Vec<u8>> {
+    let mut fish = Vec::new();
+    for line in parse::lines(data) {
+        fish.extend(parse::integer_list::<u8>(data, line, ',')?);
+    }
+
+    Ok(fish)
+}
+
+pub fn solve_one(fishies: &[u8]) -> usize {
+    simulate(fishies, 80, &mut ResultCache::default())
+}
+
+pub fn solve_two(fishies: &[u8]) -> usize {
+    simulate(fishies, 256, &mut ResultCache::default())
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<u8>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_fish(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_simulation() {
+        let fishies = parse_fish(EXAMPLE).unwrap();
+        let mut cache = ResultCache::default();
+        assert_eq!(simulate(&fishies, 18, &mut cache), 26);
+    }
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_fish(EXAMPLE).unwrap()), 5934);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_fish(EXAMPLE).unwrap()), 26984457539);
+    }
+
+    #[test]
+    fn invalid_fish() {
+        let e = parse_fish("3,4,-3,1,2\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "-3"));
+    }
+}
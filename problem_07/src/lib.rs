@@ -0,0 +1,121 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+
+pub fn minimize(x0: i64, loss: impl Fn(i64) -> i64) -> i64 {
+    let derivatives = |x| {
+        let forward = loss(x + 1);
+        let back = loss(x - 1);
+        let this = loss(x);
+        (forward - this, back - this)
+    };
+
+    let mut x = x0;
+
+    loop {
+        let (forward, back) = derivatives(x);
+        if forward > 0 && back > 0 {
+            break;
+        }
+
+        x += (back - forward).signum();
+    }
+
+    x
+}
+
+pub fn l1_loss(positions: &[u16], x: i64) -> i64 {
+    positions
+        .iter()
+        .fold(0_i64, |acc, &n| acc + (n as i64 - x).abs())
+}
+
+pub fn polynomial_loss(positions: &[u16], x: i64) -> i64 {
+    positions.iter().fold(0_i64, |acc, &n| {
+        let d = (n as i64 - x).abs();
+        // Use the sum formula and try to avoid overflow
+        acc + if d % 2 == 0 {
+            (d / 2) * (d + 1)
+        } else {
+            ((d + 1) / 2) * d
+        }
+    })
+}
+
+fn average(positions: &[u16]) -> i64 {
+    let sum = positions.iter().fold(0_i64, |acc, x| acc + *x as i64);
+    sum / positions.len() as i64
+}
+
+pub fn parse_positions(data: &str) -> Result<Vec<u16>> {
+    let mut positions = Vec::new();
+    for line in parse::lines(data) {
+        positions.extend(parse::integer_list::<u16>(data, line, ',')?);
+    }
+    if positions.is_empty() {
+        return Err(Error::at_end(data, "Input is empty"));
+    }
+
+    Ok(positions)
+}
+
+// Note: Minimizing with respect to L1 produces the median
+// Assume the loss functions are convex and have no local optima
+pub fn solve_one(positions: &[u16]) -> i64 {
+    let loss = |x| l1_loss(positions, x);
+    loss(minimize(average(positions), loss))
+}
+
+pub fn solve_two(positions: &[u16]) -> i64 {
+    let loss = |x| polynomial_loss(positions, x);
+    loss(minimize(average(positions), loss))
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<u16>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_positions(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_positions(EXAMPLE).unwrap()), 37);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_positions(EXAMPLE).unwrap()), 168);
+    }
+
+    #[test]
+    fn invalid_position() {
+        let e = parse_positions("16,1,2\n0,4x,2\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "4x"));
+
+        let e = parse_positions(",\n").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (2, 1, "Input is empty")
+        );
+    }
+}
@@ -0,0 +1,261 @@
+#[macro_use]
+extern crate lazy_static;
+
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+
+use itertools::Itertools;
+use std::collections::VecDeque;
+use std::iter::FromIterator;
+
+lazy_static! {
+    static ref CHAR_TO_DIGIT: FnvHashMap<char, i8> = [
+        ('a', 0),
+        ('b', 1),
+        ('c', 2),
+        ('d', 3),
+        ('e', 4),
+        ('f', 5),
+        ('g', 6),
+    ].iter().cloned().collect();
+    static ref DIGIT_TO_CHAR: FnvHashMap<i8, char> = {
+        // Inverse of CHAR_TO_NUM
+        let mut result = FnvHashMap::default();
+        for (&k, &v) in CHAR_TO_DIGIT.iter() {
+            result.insert(v, k);
+        }
+        result
+    };
+    static ref STR_TO_SEGMENT_VALUE: FnvHashMap<String, u8> = [
+        ("abcefg".to_owned(), 0),
+        ("cf".to_owned(), 1),
+        ("acdeg".to_owned(), 2),
+        ("acdfg".to_owned(), 3),
+        ("bcdf".to_owned(), 4),
+        ("abdfg".to_owned(), 5),
+        ("abdefg".to_owned(), 6),
+        ("acf".to_owned(), 7),
+        ("abcdefg".to_owned(), 8),
+        ("abcdfg".to_owned(), 9)
+    ].iter().cloned().collect();
+}
+
+fn deduce_impl(
+    constraints: &VecDeque<Vec<i8>>,
+    state: &mut VecDeque<i8>,
+    candidates: &mut VecDeque<i8>,
+) -> bool {
+    lazy_static! {
+        static ref CORRECT: Vec<Vec<i8>> = [
+            "cf", "acf", "bcdf", "acdeg", "acdfg", "abdfg", "abcefg", "abdefg", "abcdfg", "abcdefg"
+        ]
+        .iter()
+        .map(|s| s.chars().map(|c| CHAR_TO_DIGIT[&c]).sorted())
+        .map(Vec::from_iter)
+        .collect();
+    }
+
+    // Permute original values using current state while masking out values for which no permutation has been defined
+    let generated: FnvHashSet<Vec<i8>> = CORRECT
+        .iter()
+        .map(|m| {
+            m.iter()
+                .map(|&x| {
+                    for (i, &s) in state.iter().enumerate() {
+                        if x == i as i8 {
+                            return s;
+                        }
+                    }
+                    -1
+                })
+                .collect()
+        })
+        .collect();
+
+    #[allow(clippy::collapsible_if)]
+    let check_constraints = |g: &Vec<i8>| {
+        for c in constraints.iter().filter(|x| x.len() == g.len()) {
+            if c.len() == g.len() {
+                if g.iter().all(|x| *x == -1 || c.contains(x)) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    };
+
+    if !generated.iter().all(check_constraints) {
+        return false;
+    }
+
+    if candidates.is_empty() {
+        return true;
+    }
+
+    // Check subproblems
+    let n_candidates = candidates.len();
+    for _ in 0..n_candidates {
+        let trial = unsafe { candidates.pop_front().unwrap_unchecked() };
+
+        state.push_back(trial);
+        if deduce_impl(constraints, state, candidates) {
+            return true;
+        }
+        state.pop_back();
+
+        candidates.push_back(trial);
+    }
+
+    // Dead end
+    false
+}
+
+pub fn deduce(constraints: &VecDeque<Vec<i8>>) -> Vec<i8> {
+    // Set up internal state
+    let mut state = VecDeque::new();
+    let mut candidates = (0..7).collect();
+    deduce_impl(constraints, &mut state, &mut candidates);
+
+    // Invert permutation table
+    let mut table = vec![0; state.len()];
+    for i in 0..state.len() {
+        table[state[i] as usize] = i as i8;
+    }
+
+    table
+}
+
+pub fn decode(permutation_table: &[i8], mut msg: Vec<i8>) -> Vec<i8> {
+    for m in msg.iter_mut() {
+        *m = permutation_table[*m as usize];
+    }
+
+    msg
+}
+
+pub type MiniProblem = (VecDeque<Vec<i8>>, VecDeque<Vec<i8>>);
+
+fn parse_pattern(data: &str, s: &str) -> Result<Vec<i8>> {
+    s.chars()
+        .map(|c| {
+            CHAR_TO_DIGIT
+                .get(&c)
+                .copied()
+                .ok_or_else(|| Error::locate(data, s, "Invalid segment pattern"))
+        })
+        .collect::<Result<Vec<i8>>>()
+        .map(|v| v.into_iter().sorted().collect())
+}
+
+pub fn parse_input(data: &str) -> Result<Vec<MiniProblem>> {
+    parse::lines(data)
+        .map(|s| {
+            let (input, msg) = s
+                .split_once('|')
+                .ok_or_else(|| Error::locate(data, s, "Missing '|' separator"))?;
+            let process = |x: &str| {
+                x.split(' ')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| parse_pattern(data, s))
+                    .collect::<Result<_>>()
+            };
+            Ok((process(input)?, process(msg)?))
+        })
+        .collect()
+}
+
+// Deduce permutation table from the given miniproblem and apply it to the validation output
+pub fn solve(mini_problems: &[MiniProblem]) -> Vec<Vec<u8>> {
+    mini_problems
+        .iter()
+        .map(|(problem, msg)| {
+            let permutation_table = deduce(problem);
+
+            msg.iter()
+                .map(|m| decode(&permutation_table, m.clone()))
+                .map(|m| m.into_iter().map(|c| DIGIT_TO_CHAR[&c]).sorted().collect())
+                .map(|s: String| STR_TO_SEGMENT_VALUE[&s])
+                .collect()
+        })
+        .collect()
+}
+
+pub fn solve_one(mini_problems: &[MiniProblem]) -> usize {
+    // Count numbers corresponding to segment codes with unique lengths
+    solve(mini_problems)
+        .iter()
+        .flatten()
+        .filter(|&&x| x == 1 || x == 4 || x == 7 || x == 8)
+        .count()
+}
+
+pub fn solve_two(mini_problems: &[MiniProblem]) -> usize {
+    // Combine digits from the segment display as base-10 and then sum all of the rows
+    solve(mini_problems).iter().fold(0_usize, |acc, v| {
+        let mut value = 0;
+        for i in 0..v.len() {
+            let power = v.len() - i - 1;
+            value += 10_usize.pow(power as u32) * v[i] as usize;
+        }
+        acc + value
+    })
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<MiniProblem>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_single_entry() {
+        let entry = parse_input(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        )
+        .unwrap();
+        assert_eq!(solve(&entry), vec![vec![5, 3, 5, 3]]);
+    }
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_input(EXAMPLE).unwrap()), 26);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_input(EXAMPLE).unwrap()), 61229);
+    }
+
+    #[test]
+    fn invalid_entry() {
+        let e = parse_input("acedgfb cdfbe | cdfeb fcaxb\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 23, "fcaxb"));
+
+        let e = parse_input("acedgfb cdfbe cdfeb fcadb\n").err().unwrap();
+        assert_eq!(e.message, "Missing '|' separator");
+    }
+}
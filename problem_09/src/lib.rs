@@ -0,0 +1,274 @@
+use common::components;
+use common::error::Result;
+use common::{Array2D, Connectivity, Solution};
+use serde::Serialize;
+use std::cmp::Reverse;
+
+pub type Vec2 = (isize, isize);
+pub type HeightMap = Array2D<'static, u8, isize>;
+
+pub fn find_minima(height_map: &HeightMap) -> Vec<Vec2> {
+    height_map
+        .cells()
+        .filter(|&(p, this)| height_map.neighbors4(p).all(|n| *this < height_map[n]))
+        .map(|(p, _)| p)
+        .collect()
+}
+
+pub fn solve_one(height_map: &HeightMap, input: &[Vec2]) -> usize {
+    // Sum of x+1 over all minima x
+    input
+        .iter()
+        .fold(0, |acc, &p| acc + height_map[p] as usize + 1)
+}
+
+/// How basins are delimited.
+#[derive(Copy, Clone, Debug)]
+pub struct Options {
+    pub connectivity: Connectivity,
+    /// Cells at least this high are walls between basins
+    pub wall_height: u8,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            connectivity: Connectivity::Four,
+            wall_height: 9,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Basin {
+    pub id: usize,
+    /// The lowest cell, the first in reading order if there are several
+    pub low_point: Vec2,
+    pub size: usize,
+    /// How far the low point lies below the wall height
+    pub max_depth: u8,
+    pub mean_depth: f64,
+}
+
+/// Every basin of a height map, along with the basin that each cell belongs to.
+pub struct BasinMap {
+    pub labels: Array2D<'static, Option<usize>, isize>,
+    pub basins: Vec<Basin>,
+}
+
+// Letters used to tell basins apart in rendered maps
+const BASIN_LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl BasinMap {
+    /// The map with each basin drawn in its own letter and walls as '.'. Only the first 52
+    /// basins get a letter; any further ones are all drawn as '#'.
+    pub fn render(&self) -> Vec<String> {
+        self.labels
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|label| match label {
+                        Some(id) => BASIN_LETTERS.get(*id).map_or('#', |&c| c as char),
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self, options: &Options) -> serde_json::Value {
+        let labels: Vec<&[Option<usize>]> = self.labels.rows().collect();
+        serde_json::json!({
+            "connectivity": match options.connectivity {
+                Connectivity::Four => 4,
+                Connectivity::Eight => 8,
+            },
+            "wall_height": options.wall_height,
+            "width": self.labels.width,
+            "height": self.labels.height,
+            "basins": self.basins,
+            "labels": labels,
+        })
+    }
+}
+
+pub fn find_basins(height_map: &HeightMap, options: &Options) -> BasinMap {
+    let wall_height = options.wall_height;
+    let components =
+        components::label_components(height_map, options.connectivity, |&h| h >= wall_height);
+
+    let mut basins: Vec<Basin> = components
+        .sizes
+        .iter()
+        .enumerate()
+        .map(|(id, &size)| Basin {
+            id,
+            low_point: (0, 0),
+            size,
+            max_depth: 0,
+            mean_depth: 0.0,
+        })
+        .collect();
+
+    let mut total_depths = vec![0_usize; basins.len()];
+    for (p, &height) in height_map.cells() {
+        if let Some(id) = components.label(p) {
+            let depth = wall_height - height;
+            // Open cells are always at least 1 deep
+            if depth > basins[id].max_depth {
+                basins[id].low_point = p;
+                basins[id].max_depth = depth;
+            }
+            total_depths[id] += depth as usize;
+        }
+    }
+    for (basin, total) in basins.iter_mut().zip(total_depths) {
+        basin.mean_depth = total as f64 / basin.size as f64;
+    }
+
+    BasinMap {
+        labels: components.labels,
+        basins,
+    }
+}
+
+pub fn solve_two(height_map: &HeightMap, options: &Options) -> usize {
+    let mut sizes: Vec<usize> = find_basins(height_map, options)
+        .basins
+        .iter()
+        .map(|b| b.size)
+        .collect();
+
+    // Take the product of the 3 largest
+    sizes.sort_unstable_by_key(|&size| Reverse(size));
+
+    sizes.iter().take(3).product::<usize>()
+}
+
+pub fn parse_height_map(data: &str) -> Result<HeightMap> {
+    Array2D::parse_digits(data, data)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = HeightMap;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_height_map(data)
+    }
+
+    fn part_one(&self, height_map: &Self::Input) -> Self::AnswerOne {
+        let minima = find_minima(height_map);
+        solve_one(height_map, &minima)
+    }
+
+    fn part_two(&self, height_map: &Self::Input) -> Self::AnswerTwo {
+        solve_two(height_map, &Options::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_minima() {
+        let height_map = parse_height_map(EXAMPLE).unwrap();
+        assert_eq!(
+            find_minima(&height_map),
+            vec![(1, 0), (9, 0), (2, 2), (6, 4)]
+        );
+    }
+
+    #[test]
+    fn example_one() {
+        let height_map = parse_height_map(EXAMPLE).unwrap();
+        let minima = find_minima(&height_map);
+        assert_eq!(solve_one(&height_map, &minima), 15);
+    }
+
+    #[test]
+    fn example_two() {
+        let height_map = parse_height_map(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&height_map, &Options::default()), 1134);
+    }
+
+    #[test]
+    fn invalid_height_map() {
+        let e = parse_height_map("2199\n39a7\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "a"));
+
+        let e = parse_height_map("2199\n398\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "398"));
+    }
+
+    #[test]
+    fn crlf_input() {
+        use common::{Answer, Runnable};
+
+        let outcome = Problem.run(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(outcome.answers[0], Answer::from(15_usize));
+        assert_eq!(outcome.answers[1], Answer::from(1134_usize));
+    }
+
+    #[test]
+    fn example_basins() {
+        let height_map = parse_height_map(EXAMPLE).unwrap();
+        let basin_map = find_basins(&height_map, &Options::default());
+        assert_eq!(basin_map.basins.len(), 4);
+        assert_eq!(
+            basin_map.basins[0],
+            Basin {
+                id: 0,
+                low_point: (1, 0),
+                size: 3,
+                max_depth: 8,
+                mean_depth: 7.0,
+            }
+        );
+        assert_eq!(basin_map.labels[(9, 0)], basin_map.labels[(6, 0)]);
+        assert_eq!(basin_map.labels[(2, 0)], None);
+        assert_eq!(basin_map.render()[0], "aa...bbbbb");
+
+        let lowest: Vec<Vec2> = basin_map.basins.iter().map(|b| b.low_point).collect();
+        assert_eq!(lowest, find_minima(&height_map));
+    }
+
+    #[test]
+    fn basin_options() {
+        let height_map = parse_height_map(EXAMPLE).unwrap();
+
+        // Diagonal steps merge the basins of the example into one
+        let options = Options {
+            connectivity: Connectivity::Eight,
+            ..Options::default()
+        };
+        assert_eq!(find_basins(&height_map, &options).basins.len(), 1);
+
+        // Lower walls split the basins up
+        let options = Options {
+            wall_height: 5,
+            ..Options::default()
+        };
+        let basin_map = find_basins(&height_map, &options);
+        assert_eq!(basin_map.basins[0].size, 3);
+        assert_eq!(basin_map.basins[0].max_depth, 4);
+        assert_eq!(basin_map.labels[(4, 0)], None);
+    }
+
+    #[test]
+    fn render_many_basins() {
+        let data = "09".repeat(60);
+        let basin_map = find_basins(&parse_height_map(&data).unwrap(), &Options::default());
+        assert_eq!(basin_map.basins.len(), 60);
+
+        let row: Vec<char> = basin_map.render()[0].chars().collect();
+        assert_eq!((row[0], row[1], row[102]), ('a', '.', 'Z'));
+        assert_eq!((row[104], row[118]), ('#', '#'));
+    }
+}
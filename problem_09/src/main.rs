@@ -1,148 +1,121 @@
-use smallvec::smallvec;
-use smallvec::SmallVec;
-
-use fnv::FnvHashSet;
-use std::cmp::Reverse;
-use std::collections::VecDeque;
-
-type Vec2 = (isize, isize);
+use common::output::{self, Format};
+use common::{input, solution, Connectivity};
+use problem_09::{find_basins, parse_height_map, Options};
+
+/// Remove `--connectivity 4|8` and `--wall-height N` from `args`.
+fn take_options(args: &mut Vec<String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    if let Some(i) = args.iter().position(|a| a == "--connectivity") {
+        let value = args.get(i + 1).ok_or("Missing value for --connectivity")?;
+        options.connectivity = match value.as_str() {
+            "4" => Connectivity::Four,
+            "8" => Connectivity::Eight,
+            _ => return Err(format!("Invalid connectivity \"{}\"", value)),
+        };
+        args.drain(i..i + 2);
+    }
+    if let Some(i) = args.iter().position(|a| a == "--wall-height") {
+        let value = args.get(i + 1).ok_or("Missing value for --wall-height")?;
+        options.wall_height = value
+            .parse()
+            .ok()
+            .filter(|&h| (1..=10).contains(&h))
+            .ok_or(format!("Invalid wall height \"{}\"", value))?;
+        args.drain(i..i + 2);
+    }
 
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
+    Ok(options)
 }
 
-fn at<T>(data: &[T], (width, height): Vec2, (x, y): Vec2) -> Option<&T> {
-    if x < 0 || x >= width || y < 0 || y >= height {
-        return None;
+/// Print the basins of the height map in `path`: a summary line per basin followed by the
+/// lettered map for text, or everything including each cell's basin id for JSON.
+fn export_basins(
+    path: &str,
+    options: &Options,
+    format: Format,
+) -> Result<Option<serde_json::Value>, String> {
+    let name = solution::display_name(path);
+    let data = solution::read_input(path)?;
+    let height_map = input::normalize(&data)
+        .and_then(|data| parse_height_map(&data))
+        .map_err(|e| e.with_file(name).to_string())?;
+    let basin_map = find_basins(&height_map, options);
+
+    if format == Format::Json {
+        let mut json = basin_map.to_json(options);
+        json["input"] = name.into();
+        return Ok(Some(json));
     }
 
-    let index = y * width + x;
-    unsafe { Some(data.get_unchecked(index as usize)) }
-}
+    for basin in &basin_map.basins {
+        let (x, y) = basin.low_point;
+        println!(
+            "Basin {}: low point {},{}, size {}, max depth {}, mean depth {:.2}",
+            basin.id, x, y, basin.size, basin.max_depth, basin.mean_depth
+        );
+    }
+    println!();
+    for row in basin_map.render() {
+        println!("{}", row);
+    }
+    println!();
 
-unsafe fn at_unchecked<T>(data: &[T], (width, _): Vec2, (x, y): Vec2) -> &T {
-    let index = y * width + x;
-    data.get_unchecked(index as usize)
+    Ok(None)
 }
 
-fn explore_basin(data: &[u8], dim @ (width, height): Vec2, start_p: Vec2) -> FnvHashSet<Vec2> {
-    use Direction::*;
-
-    let outset: (Vec2, SmallVec<[_; 4]>) = (start_p, smallvec![Left, Right, Up, Down]);
-    let mut frontier = VecDeque::from([outset]);
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} --basins [--connectivity 4|8] [--wall-height N] [--format json|text] \
+         problem_input... (- for stdin)",
+        program
+    );
+    std::process::exit(-1);
+}
 
-    let mut visited = FnvHashSet::default();
-    while let Some((p @ (x, y), dirs)) = frontier.pop_front() {
-        let &this = unsafe { at_unchecked(data, dim, p) };
-        if this >= 9 || !visited.insert(p) {
-            continue;
+fn main() {
+    let mut args: Vec<String> = std::env::args().collect();
+    match args.iter().skip(1).position(|a| a == "--basins") {
+        Some(i) => {
+            args.remove(i + 1);
         }
-        for d in dirs {
-            let next @ ((u, v), _) = match d {
-                Left => ((x - 1, y), smallvec![Left, Up, Down]),
-                Right => ((x + 1, y), smallvec![Right, Up, Down]),
-                Up => ((x, y - 1), smallvec![Left, Right, Up]),
-                Down => ((x, y + 1), smallvec![Left, Right, Down]),
-            };
-            if u >= 0 && u < width && v >= 0 && v < height {
-                frontier.push_back(next);
-            }
+        None => {
+            solution::main(9, &problem_09::Problem);
+            return;
         }
     }
 
-    visited
-}
-
-fn find_minima(height_map: &[u8], dim @ (width, height): Vec2) -> Vec<Vec2> {
-    let is_minimum = |(x, y)| {
-        let this = unsafe { at_unchecked(height_map, dim, (x, y)) };
-
-        let neighbors = [(x, y - 1), (x - 1, y), (x + 1, y), (x, y + 1)];
-        for p in neighbors {
-            if let Some(adj) = at(height_map, dim, p) {
-                if this >= adj {
-                    return false;
-                }
+    let parsed = output::take_format(&mut args).and_then(|format| {
+        if format == Format::Csv {
+            return Err("Basins cannot be exported as CSV".to_owned());
+        }
+        Ok((format, take_options(&mut args)?))
+    });
+    let (format, options) = match parsed {
+        Ok(parsed) if args.len() >= 2 => parsed,
+        result => {
+            if let Err(e) = result {
+                eprintln!("{}", e);
             }
+            usage(&args[0]);
         }
-
-        true
     };
 
-    let mut minima = Vec::new();
-    let mut y = 0;
-    while y < height {
-        let mut x = 0;
-        while x < width {
-            let p = (x, y);
-            if is_minimum(p) {
-                minima.push(p);
-
-                // Skip next in line since it is by definition not a minimum
-                x += 1;
+    let mut failed = false;
+    let mut exports = Vec::new();
+    for path in &args[1..] {
+        match export_basins(path, &options, format) {
+            Ok(json) => exports.extend(json),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
             }
-
-            x += 1;
         }
-
-        y += 1;
     }
-
-    minima
-}
-
-fn solve_one(height_map: &[u8], dim: Vec2, input: &[Vec2]) -> usize {
-    // Sum of x+1 over all minima x
-    input.iter().fold(0, |acc, &p| {
-        let &val = unsafe { at_unchecked(height_map, dim, p) };
-        acc + val as usize + 1
-    })
-}
-
-fn solve_two(height_map: &[u8], dim: Vec2, input: &[Vec2]) -> usize {
-    // Find unique basins
-    let mut basins: Vec<FnvHashSet<Vec2>> = input
-        .iter()
-        .map(|&p| explore_basin(height_map, dim, p))
-        .collect();
-    basins.dedup();
-
-    // Take the product of the 3 largest
-    basins.sort_by_key(|x| Reverse(x.len()));
-
-    basins.iter().map(|x| x.len()).take(3).product::<usize>()
-}
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: {} [problem_input]", args[0]);
-        std::process::exit(-1);
+    if format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&exports).unwrap());
     }
 
-    let data = std::fs::read_to_string(&args[1]).expect("Unable to open input file");
-    let mut rows = data.split('\n').filter(|s| !s.is_empty()).peekable();
-
-    // Peek first element to ascertain width
-    let first = rows.peek().expect("Input is empty");
-    let width = first.len() as isize;
-
-    let height_map: Vec<u8> = rows
-        .flat_map(|s| s.chars())
-        .map(|c| c as u8 - b'0')
-        .collect();
-
-    let height = height_map.len() as isize / width;
-    let dim = (width, height);
-
-    let minima = find_minima(&height_map, dim);
-
-    let answer_one = solve_one(&height_map, dim, &minima);
-    let answer_two = solve_two(&height_map, dim, &minima);
-
-    println!("{}", answer_one);
-    println!("{}", answer_two);
+    if failed {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,139 @@
+#[macro_use]
+extern crate lazy_static;
+
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use fnv::FnvHashMap;
+
+pub fn score_line(input: &str) -> (usize, usize) {
+    lazy_static! {
+        static ref CORRUPT_MAP: FnvHashMap<char, usize> =
+            [(')', 3), (']', 57), ('}', 1197), ('>', 25137)]
+                .iter()
+                .cloned()
+                .collect();
+        static ref INCOMPLETE_MAP: FnvHashMap<char, usize> =
+            [(')', 1), (']', 2), ('}', 3), ('>', 4)]
+                .iter()
+                .cloned()
+                .collect();
+        static ref CLOSER_MAP: FnvHashMap<char, char> =
+            [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]
+                .iter()
+                .cloned()
+                .collect();
+    }
+
+    let corrupt = |x| CORRUPT_MAP.get(&x).cloned().unwrap_or(0);
+    let incomplete = |x| INCOMPLETE_MAP.get(&x).cloned().unwrap_or(0);
+
+    let mut expected_closers = Vec::new();
+
+    for c in input.chars() {
+        if let Some(&closer) = CLOSER_MAP.get(&c) {
+            expected_closers.push(closer);
+        } else if let Some(expected) = expected_closers.pop() {
+            if c != expected {
+                return (corrupt(c), 0);
+            }
+        } else {
+            return (corrupt(c), 0);
+        }
+    }
+
+    // Line is only incomplete
+    let incomplete_score = expected_closers
+        .into_iter()
+        .rev()
+        .fold(0, |acc, c| acc * 5 + incomplete(c));
+
+    (0, incomplete_score)
+}
+
+pub fn parse_lines(data: &str) -> Result<Vec<String>> {
+    let lines = parse::lines(data)
+        .map(|s| {
+            if let Some(i) = s.find(|c| !"()[]{}<>".contains(c)) {
+                let c = s[i..].chars().next().unwrap();
+                return Err(Error::locate(
+                    data,
+                    &s[i..i + c.len_utf8()],
+                    "Expected bracket",
+                ));
+            }
+            Ok(s.to_owned())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if lines.is_empty() {
+        return Err(Error::at_end(data, "Input is empty"));
+    }
+
+    Ok(lines)
+}
+
+pub fn solve_one(lines: &[String]) -> usize {
+    lines.iter().map(|s| score_line(s).0).sum::<usize>()
+}
+
+pub fn solve_two(lines: &[String]) -> usize {
+    let mut incomplete: Vec<_> = lines.iter().map(|s| score_line(s).1).collect();
+
+    incomplete.retain(|&x| x != 0);
+    incomplete.sort_unstable();
+    incomplete[incomplete.len() / 2]
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<String>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_lines(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_scores() {
+        assert_eq!(score_line("{([(<{}[<>[]}>{[]{[(<()>"), (1197, 0));
+        assert_eq!(score_line("<{([{{}}[<[[[<>{}]]]>[]]"), (0, 294));
+    }
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_lines(EXAMPLE).unwrap()), 26397);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_lines(EXAMPLE).unwrap()), 288957);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let e = parse_lines("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>x\n")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 22, "x"));
+
+        let e = parse_lines("\n").err().unwrap();
+        assert_eq!(e.message, "Input is empty");
+    }
+}
@@ -0,0 +1,122 @@
+use common::error::Result;
+use common::{Array2D, Solution};
+
+pub type EnergyMap = Array2D<'static, u8, isize>;
+
+pub fn simulate(input: &mut EnergyMap) -> usize {
+    // Use highest bit as flash indicator
+    let has_flashed = |x| x & (1 << 7) != 0;
+    let flashed = |x| x | 1 << 7;
+    let value = |x| x & !(1_u8 << 7);
+
+    for e in input.values_mut() {
+        *e += 1;
+    }
+
+    loop {
+        let mut no_flashes = true;
+
+        for y in 0..input.height {
+            for x in 0..input.width {
+                let this = &mut input[(x, y)];
+                if value(*this) > 9 && !has_flashed(*this) {
+                    // Mark as flashed
+                    *this = flashed(*this);
+                    no_flashes = false;
+
+                    // Trigger neighbors
+                    for n in input.neighbors8((x, y)) {
+                        input[n] += 1;
+                    }
+                }
+            }
+        }
+
+        if no_flashes {
+            break;
+        }
+    }
+
+    // Count flashes and reset energy levels
+    let mut flashes = 0;
+    for e in input.values_mut() {
+        if has_flashed(*e) {
+            flashes += 1;
+            *e = 0;
+        }
+    }
+
+    flashes
+}
+
+pub fn parse_energy_map(data: &str) -> Result<EnergyMap> {
+    Array2D::parse_digits(data, data)
+}
+
+pub fn solve_one(energy_map: &EnergyMap) -> usize {
+    let mut energy_map = energy_map.clone();
+
+    let mut flashes = 0;
+    for _ in 0..100 {
+        flashes += simulate(&mut energy_map);
+    }
+
+    flashes
+}
+
+pub fn solve_two(energy_map: &EnergyMap) -> usize {
+    let mut energy_map = energy_map.clone();
+    let size = energy_map.values().count();
+
+    let mut step = 1;
+    while simulate(&mut energy_map) != size {
+        step += 1;
+    }
+
+    step
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = EnergyMap;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_energy_map(data)
+    }
+
+    fn part_one(&self, energy_map: &Self::Input) -> Self::AnswerOne {
+        solve_one(energy_map)
+    }
+
+    fn part_two(&self, energy_map: &Self::Input) -> Self::AnswerTwo {
+        solve_two(energy_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let energy_map = parse_energy_map(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&energy_map), 1656);
+    }
+
+    #[test]
+    fn example_two() {
+        let energy_map = parse_energy_map(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&energy_map), 195);
+    }
+
+    #[test]
+    fn invalid_energy_map() {
+        let e = parse_energy_map("5483\n27-5\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "-"));
+    }
+}
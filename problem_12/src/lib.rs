@@ -0,0 +1,223 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use fnv::FnvHashMap;
+use smallvec::SmallVec;
+use stable_vec::StableVec;
+use std::collections::hash_map::Entry::*;
+use std::iter::FromIterator;
+
+enum NodeType {
+    SingleVisit,
+    MultiVisit,
+}
+
+struct Node {
+    pub node_type: NodeType,
+    pub connections: SmallVec<[u8; 4]>,
+}
+
+#[derive(Default)]
+pub struct NodeStorage {
+    data: StableVec<Node>,
+    index: FnvHashMap<String, u8>,
+}
+
+impl NodeStorage {
+    fn connect(&mut self, name_a: &str, name_b: &str) {
+        let index_a = self.index[name_a];
+        let index_b = self.index[name_b];
+
+        // Make sure the start node is only visited once
+        if name_b != "start" {
+            let a = unsafe { self.data.get_unchecked_mut(index_a as usize) };
+            a.connections.push(index_b);
+        }
+        if name_a != "start" {
+            let b = unsafe { self.data.get_unchecked_mut(index_b as usize) };
+            b.connections.push(index_a);
+        }
+    }
+
+    fn insert(&mut self, name: String) {
+        if let Vacant(e) = self.index.entry(name) {
+            let key = e.key();
+            let first = key.chars().next().unwrap();
+
+            let node_type = if first.is_lowercase() {
+                NodeType::SingleVisit
+            } else {
+                NodeType::MultiVisit
+            };
+
+            let index = self.data.push(Node {
+                node_type,
+                connections: SmallVec::new(),
+            });
+            e.insert(index as u8);
+        }
+    }
+
+    pub fn paths(&self, start: u8, end: u8, may_double: bool) -> usize {
+        let allowed_counts = FnvHashMap::from_iter(self.data.iter().filter_map(|(i, n)| {
+            if let NodeType::SingleVisit = n.node_type {
+                Some((i as u8, 1))
+            } else {
+                None
+            }
+        }));
+
+        let mut explore_queue: SmallVec<[_; 32]> = SmallVec::new();
+        explore_queue.push((start, allowed_counts, may_double));
+
+        let mut result = 0;
+        while let Some((this, mut allowed, mut allow_double)) = explore_queue.pop() {
+            if this == end {
+                result += 1;
+                continue;
+            }
+
+            let node = unsafe { self.data.get_unchecked(this as usize) };
+
+            if let NodeType::SingleVisit = node.node_type {
+                if let Occupied(ref mut e) = allowed.entry(this) {
+                    let count = e.get_mut();
+
+                    if *count == 0 {
+                        if allow_double {
+                            allow_double = false;
+                        } else {
+                            continue;
+                        }
+                    } else {
+                        *count = 0;
+                    }
+                }
+            }
+
+            for &c in node.connections.iter() {
+                explore_queue.push((c, allowed.clone(), allow_double));
+            }
+        }
+
+        result
+    }
+}
+
+pub fn parse_input(data: &str) -> Result<NodeStorage> {
+    let mut storage = NodeStorage::default();
+    for (a, b) in parse::rules(data, data, "-")? {
+        storage.insert(a.to_owned());
+        storage.insert(b.to_owned());
+        storage.connect(a, b);
+    }
+
+    for name in ["start", "end"] {
+        if !storage.index.contains_key(name) {
+            return Err(Error::at_end(data, format!("Missing \"{}\" cave", name)));
+        }
+    }
+
+    Ok(storage)
+}
+
+pub fn solve_one(storage: &NodeStorage) -> usize {
+    storage.paths(storage.index["start"], storage.index["end"], false)
+}
+
+pub fn solve_two(storage: &NodeStorage) -> usize {
+    storage.paths(storage.index["start"], storage.index["end"], true)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = NodeStorage;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    const SLIGHTLY_LARGER_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const EVEN_LARGER_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_input(EXAMPLE).unwrap()), 10);
+        assert_eq!(
+            solve_one(&parse_input(SLIGHTLY_LARGER_EXAMPLE).unwrap()),
+            19
+        );
+        assert_eq!(solve_one(&parse_input(EVEN_LARGER_EXAMPLE).unwrap()), 226);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_input(EXAMPLE).unwrap()), 36);
+        assert_eq!(
+            solve_two(&parse_input(SLIGHTLY_LARGER_EXAMPLE).unwrap()),
+            103
+        );
+        assert_eq!(solve_two(&parse_input(EVEN_LARGER_EXAMPLE).unwrap()), 3509);
+    }
+
+    #[test]
+    fn invalid_connection() {
+        let e = parse_input("start-A\nA_b\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "A_b"));
+
+        let e = parse_input("start-A\nA-b\n").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (3, 1, "Missing \"end\" cave")
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use common::error::{Error, Result};
+use common::{parse, Answer, Solution, SparseGrid};
+
+pub type Vec2 = (i32, i32);
+pub type Points = SparseGrid<(), i32>;
+
+#[derive(Copy, Clone)]
+pub enum Fold {
+    Vertical(i32),
+    Horizontal(i32),
+}
+
+pub fn parse_input(input: &str) -> Result<(Points, Vec<Fold>)> {
+    let mut sections = parse::sections(input);
+    let dots = sections.next().unwrap_or("");
+    let instructions = sections.next().unwrap_or("");
+
+    let mut points = Points::new();
+    for (x, y) in parse::rules(input, dots, ",")? {
+        points.insert((parse::integer(input, x)?, parse::integer(input, y)?), ());
+    }
+
+    // Folds are given as "fold along coord_name=coord_value"
+    let mut folds = Vec::new();
+    for r in parse::lines(instructions) {
+        let expected = || Error::locate(input, r, "Expected fold instruction");
+        let (name, value) = r
+            .strip_prefix("fold along ")
+            .ok_or_else(expected)
+            .and_then(|s| parse::key_value(input, s, "=").map_err(|_| expected()))?;
+        let value = parse::integer(input, value)?;
+
+        match name {
+            "x" => folds.push(Fold::Horizontal(value)),
+            "y" => folds.push(Fold::Vertical(value)),
+            _ => return Err(expected()),
+        }
+    }
+
+    if points.is_empty() {
+        return Err(Error::at_end(input, "No dots found"));
+    }
+    if folds.is_empty() {
+        return Err(Error::at_end(input, "No fold instructions found"));
+    }
+
+    Ok((points, folds))
+}
+
+pub fn fold(points: &mut Points, (w, h): Vec2, f: Fold) -> Vec2 {
+    let (reflected, new_dim): (Vec<Vec2>, Vec2) = match f {
+        Fold::Horizontal(new_w) => (
+            points
+                .points()
+                .filter(|&(x, y)| x >= new_w && x <= w && y >= 0 && y <= h)
+                .map(|(x, y)| (2 * new_w - x, y))
+                .collect(),
+            (new_w, h),
+        ),
+        Fold::Vertical(new_h) => (
+            points
+                .points()
+                .filter(|&(x, y)| x >= 0 && x <= w && y >= new_h && y <= h)
+                .map(|(x, y)| (x, 2 * new_h - y))
+                .collect(),
+            (w, new_h),
+        ),
+    };
+
+    for r in reflected {
+        points.insert(r, ());
+    }
+
+    points.retain(|(x, y), _| x >= 0 && x <= new_dim.0 && y >= 0 && y <= new_dim.1);
+
+    new_dim
+}
+
+/// The bottom right corner of the paper, inclusive.
+pub fn bounds(points: &Points) -> Vec2 {
+    points.bounds().map(|(_, max)| max).unwrap()
+}
+
+pub fn render(points: &Points, dim: Vec2) -> Vec<String> {
+    points.render_region((0, 0), dim, |p| if p.is_some() { '#' } else { '.' })
+}
+
+pub fn solve_one(points: &Points, folds: &[Fold]) -> usize {
+    let mut points = points.clone();
+    let dim = bounds(&points);
+
+    fold(&mut points, dim, *folds.first().unwrap());
+    points.len()
+}
+
+pub fn solve_two(points: &Points, folds: &[Fold]) -> Answer {
+    let mut points = points.clone();
+    let mut dim = bounds(&points);
+
+    for &f in folds {
+        dim = fold(&mut points, dim, f);
+    }
+
+    Answer::Grid(render(&points, dim))
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = (Points, Vec<Fold>);
+    type AnswerOne = usize;
+    type AnswerTwo = Answer;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, (points, folds): &Self::Input) -> Self::AnswerOne {
+        solve_one(points, folds)
+    }
+
+    fn part_two(&self, (points, folds): &Self::Input) -> Self::AnswerTwo {
+        solve_two(points, folds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let (points, folds) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&points, &folds), 17);
+    }
+
+    #[test]
+    fn example_two() {
+        let (points, folds) = parse_input(EXAMPLE).unwrap();
+        let expected = "\
+#####
+#...#
+#...#
+#...#
+#####
+.....
+.....";
+        assert_eq!(solve_two(&points, &folds).to_string(), expected);
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let e = parse_input("6,10\n0,14\n\nfold along z=7\n").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str()),
+            (4, 1, "fold along z=7")
+        );
+
+        let e = parse_input("6,10\n0,14\n").err().unwrap();
+        assert_eq!(e.message, "No fold instructions found");
+    }
+}
@@ -0,0 +1,189 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use fnv::FnvHashMap;
+
+pub type MutationMap = FnvHashMap<String, (String, String)>;
+
+pub fn parse_input(input: &str) -> Result<(Vec<char>, MutationMap)> {
+    let mut sections = parse::sections(input);
+    let template_str = sections.next().unwrap_or("").trim_end();
+    let rules_str = sections
+        .next()
+        .ok_or_else(|| Error::at_end(input, "Missing insertion rules"))?;
+
+    let template: Vec<char> = template_str.chars().collect();
+
+    let rules: MutationMap = parse::rules(input, rules_str, " -> ")?
+        .into_iter()
+        .map(|(from, to)| {
+            let corrupt = || Error::locate(input, from, "Expected rule \"AB -> C\"");
+            let mut to_chars = to.chars();
+            let to = to_chars.next().ok_or_else(corrupt)?;
+            if from.chars().count() != 2 || to_chars.next().is_some() {
+                return Err(corrupt());
+            }
+
+            let first: String = from.chars().take(1).chain(std::iter::once(to)).collect();
+            let second: String = std::iter::once(to).chain(from.chars().skip(1)).collect();
+
+            Ok((from.to_owned(), (first, second)))
+        })
+        .collect::<Result<_>>()?;
+
+    // Every pair that can occur must have a rule, since mutate looks them up unchecked
+    for (i, win) in template.windows(2).enumerate() {
+        let pair: String = win.iter().collect();
+        if !rules.contains_key(&pair) {
+            let fragment = &template_str[i..i + pair.len()];
+            return Err(Error::locate(input, fragment, "No rule for pair"));
+        }
+    }
+    for (from, (a, b)) in rules.iter() {
+        if !rules.contains_key(a) || !rules.contains_key(b) {
+            let line = rules_str
+                .split('\n')
+                .find(|s| s.starts_with(from.as_str()))
+                .unwrap();
+            return Err(Error::locate(
+                input,
+                line,
+                "Rule produces a pair without a rule",
+            ));
+        }
+    }
+
+    Ok((template, rules))
+}
+
+fn count_difference(input: &FnvHashMap<char, isize>) -> isize {
+    let mut mincount = isize::MAX;
+    let mut maxcount = isize::MIN;
+    for &count in input.values() {
+        if count < mincount {
+            mincount = count;
+        }
+        if count > maxcount {
+            maxcount = count;
+        }
+    }
+
+    maxcount - mincount
+}
+
+pub fn mutate(rules: &MutationMap, input: &[char], iterations: usize) -> isize {
+    // Make sure all valid fragments have entries
+    let mut fragment_counts = FnvHashMap::default();
+    for from in rules.keys() {
+        fragment_counts.entry(from.as_str()).or_insert(0_isize);
+    }
+
+    // Count fragments in input
+    for win in input.windows(2) {
+        let candidate: String = win.iter().collect();
+        let e = unsafe {
+            fragment_counts
+                .get_mut(candidate.as_str())
+                .unwrap_unchecked()
+        };
+        *e += 1;
+    }
+
+    let mut letter_counts = FnvHashMap::default();
+
+    // Mutate
+    for _ in 0..iterations {
+        // Save fragment counter updates since we cannot lookup an element while already borrowing another
+        let mut counter_updates = Vec::new();
+        for (k, count) in fragment_counts.iter_mut().filter(|(_, count)| **count > 0) {
+            if let Some((ref a, ref b)) = rules.get(*k) {
+                // Split pair as per rule
+                counter_updates.push((a.as_str(), *count));
+                counter_updates.push((b.as_str(), *count));
+
+                // Compensate letter count for shared letters
+                let shared_letter = unsafe { b.chars().next().unwrap_unchecked() };
+                let e = letter_counts.entry(shared_letter).or_insert(0);
+                *e -= *count;
+
+                *count = 0;
+            }
+        }
+
+        // Update fragment counters
+        for (k, count) in counter_updates {
+            let v = unsafe { fragment_counts.get_mut(k).unwrap_unchecked() };
+            *v += count;
+        }
+    }
+
+    // Count letters
+    for (k, v) in fragment_counts {
+        let mut char_iter = k.chars();
+        let first = unsafe { char_iter.next().unwrap_unchecked() };
+        let second = unsafe { char_iter.next().unwrap_unchecked() };
+
+        let a = letter_counts.entry(first).or_insert(0);
+        *a += v;
+        let b = letter_counts.entry(second).or_insert(0);
+        *b += v;
+    }
+
+    count_difference(&letter_counts)
+}
+
+pub fn solve_one(template: &[char], rules: &MutationMap) -> isize {
+    mutate(rules, template, 10)
+}
+
+pub fn solve_two(template: &[char], rules: &MutationMap) -> isize {
+    mutate(rules, template, 40)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = (Vec<char>, MutationMap);
+    type AnswerOne = isize;
+    type AnswerTwo = isize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, (template, rules): &Self::Input) -> Self::AnswerOne {
+        solve_one(template, rules)
+    }
+
+    fn part_two(&self, (template, rules): &Self::Input) -> Self::AnswerTwo {
+        solve_two(template, rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        let (template, rules) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_one(&template, &rules), 1588);
+    }
+
+    #[test]
+    fn example_two() {
+        let (template, rules) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_two(&template, &rules), 2188189693529);
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let e = parse_input("NNCB\n\nCH -> B\nHH - N\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 1, "HH - N"));
+
+        let e = parse_input(&EXAMPLE.replace("NNCB", "NNCX")).err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 3, "CX"));
+    }
+}
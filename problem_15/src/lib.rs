@@ -0,0 +1,305 @@
+use common::error::Result;
+use common::search;
+use common::{Array2D, Grid, Solution, SparseGrid};
+
+/// Coordinates, wide enough for heavily tiled maps
+pub type Integer = i32;
+pub type Vec2 = (Integer, Integer);
+pub type Risk = u32;
+
+/// How many times the map is repeated in each direction in part two
+pub const TILES: Integer = 5;
+
+// Edge risks fit in a u8, so every pending risk lies within 256 of the current one
+const BUCKETS: usize = u8::MAX as usize + 1;
+
+/// The lowest total risk of a path from `start` to `end`, not counting the risk of `start`,
+/// along with the path itself.
+///
+/// Uses Dial's algorithm: Dijkstra with a ring of buckets indexed by risk in place of a heap,
+/// and risks stored in a dense array indexed by cell.
+///
+/// # Panics
+/// If the map has too many cells to index on this platform.
+pub fn lowest_risk_path<G>(map: &G, start: Vec2, end: Vec2) -> Option<(Risk, Vec<Vec2>)>
+where
+    G: Grid<Coord = Integer, Value = u8>,
+{
+    let (x0, y0) = map.origin();
+    let (width, height) = map.dimensions();
+    let cells = (width as usize)
+        .checked_mul(height as usize)
+        .filter(|&cells| cells <= isize::MAX as usize / std::mem::size_of::<Vec2>())
+        .expect("Map too large");
+    let index = |(x, y): Vec2| (y - y0) as usize * width as usize + (x - x0) as usize;
+    if !map.in_bounds(start) || !map.in_bounds(end) {
+        return None;
+    }
+
+    let mut risks = vec![Risk::MAX; cells];
+    let mut previous = vec![start; cells];
+    let mut buckets = vec![Vec::new(); BUCKETS];
+    risks[index(start)] = 0;
+    buckets[0].push(start);
+    let mut pending = 1;
+
+    let mut current: Risk = 0;
+    while pending > 0 {
+        let bucket = current as usize % BUCKETS;
+        while let Some(v) = buckets[bucket].pop() {
+            pending -= 1;
+
+            // Skip cells that have since been reached with a lower risk
+            if risks[index(v)] != current {
+                continue;
+            }
+            if v == end {
+                let mut path = vec![end];
+                while path[path.len() - 1] != start {
+                    path.push(previous[index(path[path.len() - 1])]);
+                }
+                path.reverse();
+
+                return Some((current, path));
+            }
+
+            for n in map.neighbors4(v) {
+                let edge_risk = map.get(n).unwrap();
+                let risk = current + edge_risk as Risk;
+                if risk < risks[index(n)] {
+                    risks[index(n)] = risk;
+                    previous[index(n)] = v;
+                    buckets[risk as usize % BUCKETS].push(n);
+                    pending += 1;
+                }
+            }
+        }
+
+        current += 1;
+    }
+
+    None
+}
+
+pub fn lowest_risk<G>(map: &G, start: Vec2, end: Vec2) -> Option<Risk>
+where
+    G: Grid<Coord = Integer, Value = u8>,
+{
+    lowest_risk_path(map, start, end).map(|(risk, _)| risk)
+}
+
+/// How `render_path` marks the cells on the path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    /// Draw path cells as '*'
+    Marker,
+    /// Keep the risk of path cells but show it in bold reverse video, for terminals
+    Ansi,
+}
+
+/// A risk as a single character: its base 36 digit up to 35, and '#' above that.
+fn risk_symbol(risk: u8) -> char {
+    char::from_digit(risk as u32, 36).unwrap_or('#')
+}
+
+/// Show the risk of every cell of the map, with the cells on `path` highlighted.
+pub fn render_path<G>(map: &G, path: &[Vec2], highlight: Highlight) -> Vec<String>
+where
+    G: Grid<Coord = Integer, Value = u8>,
+{
+    let mut on_path = SparseGrid::new();
+    for &p in path {
+        on_path.insert(p, ());
+    }
+
+    let (x0, y0) = map.origin();
+    let (width, height) = map.dimensions();
+    (y0..y0 + height)
+        .map(|y| {
+            let mut row = String::new();
+            for x in x0..x0 + width {
+                let symbol = risk_symbol(map.get((x, y)).unwrap());
+                match (on_path.contains((x, y)), highlight) {
+                    (false, _) => row.push(symbol),
+                    (true, Highlight::Marker) => row.push('*'),
+                    (true, Highlight::Ansi) => row.push_str(&format!("\x1b[1;7m{}\x1b[0m", symbol)),
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+/// The untiled map is small enough for the general purpose search.
+pub fn solve_one(input: &Array2D<u8, Integer>) -> Risk {
+    let end = (input.width - 1, input.height - 1);
+    let successors = |&v: &Vec2| Grid::neighbors4(input, v).map(move |n| (n, input[n] as Risk));
+
+    search::dijkstra((0, 0), successors, |&v| v == end)
+        .map(|(risk, _)| risk)
+        .expect("No path exists")
+}
+
+/// The map repeated `tiles` times in each direction. The risk increases by the x and y tile
+/// number but rolls over from 9 to 1. The first tile is the map itself.
+pub fn tiled_map<'a>(
+    input: &'a Array2D<'a, u8, Integer>,
+    tiles: Integer,
+) -> impl Grid<Coord = Integer, Value = u8> + 'a {
+    input.tiled((tiles, tiles), |&n, (x_tile, y_tile)| {
+        if (x_tile, y_tile) == (0, 0) {
+            n
+        } else {
+            ((n as Integer + x_tile + y_tile - 1) % 9 + 1) as u8
+        }
+    })
+}
+
+pub fn solve_tiled(input: &Array2D<u8, Integer>, tiles: Integer) -> Risk {
+    let tiled = tiled_map(input, tiles);
+    let (width, height) = tiled.dimensions();
+
+    lowest_risk(&tiled, (0, 0), (width - 1, height - 1)).expect("No path exists")
+}
+
+pub fn solve_two(input: &Array2D<u8, Integer>) -> Risk {
+    solve_tiled(input, TILES)
+}
+
+pub fn parse_map(data: &str) -> Result<Array2D<'static, u8, Integer>> {
+    Array2D::parse_digits(data, data)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Array2D<'static, u8, Integer>;
+    type AnswerOne = Risk;
+    type AnswerTwo = Risk;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_map(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_map(EXAMPLE).unwrap()), 40);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_map(EXAMPLE).unwrap()), 315);
+    }
+
+    #[test]
+    fn invalid_map() {
+        let e = parse_map("1163\n13 1\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, " "));
+    }
+
+    #[test]
+    fn tile_multiplier() {
+        let map = parse_map(EXAMPLE).unwrap();
+        assert_eq!(solve_tiled(&map, 1), 40);
+        assert_eq!(solve_tiled(&map, 5), 315);
+
+        // The first tile is left alone, even where it has zero risk
+        let map = parse_map("100\n050\n001\n").unwrap();
+        assert_eq!(solve_one(&map), 1);
+        assert_eq!(solve_tiled(&map, 1), solve_one(&map));
+    }
+
+    #[test]
+    fn heavy_risks() {
+        // Risks above 9 must not be lost when the bucket ring wraps around
+        let map = Array2D::from_vec(vec![0, 15, 3, 1, 255, 1], (3, 2));
+        assert_eq!(lowest_risk(&map, (0, 0), (2, 0)), Some(18));
+        assert_eq!(lowest_risk(&map, (0, 0), (1, 1)), Some(256));
+        assert_eq!(lowest_risk(&map, (0, 0), (3, 0)), None);
+
+        let (_, path) = lowest_risk_path(&map, (0, 0), (2, 0)).unwrap();
+        assert_eq!(render_path(&map, &path, Highlight::Marker), ["***", "1#1"]);
+        let (_, path) = lowest_risk_path(&map, (0, 0), (1, 1)).unwrap();
+        assert_eq!(render_path(&map, &path, Highlight::Marker), ["*f3", "**1"]);
+        assert_eq!(
+            render_path(&map, &path, Highlight::Ansi)[1],
+            "\x1b[1;7m1\x1b[0m\x1b[1;7m#\x1b[0m1"
+        );
+    }
+
+    #[test]
+    fn origin() {
+        let mut map = SparseGrid::new();
+        for (p, risk) in [((-2, -1), 1), ((-1, -1), 2), ((-1, 0), 3)] {
+            map.insert(p, risk);
+        }
+        let (risk, path) = lowest_risk_path(&Filled(&map), (-2, -1), (-1, 0)).unwrap();
+        assert_eq!((risk, path.len()), (5, 3));
+        assert_eq!(
+            render_path(&Filled(&map), &path, Highlight::Marker),
+            ["**", "#*"]
+        );
+    }
+
+    /// A sparse map with the unoccupied cells at maximum risk
+    struct Filled<'a>(&'a SparseGrid<u8>);
+
+    impl Grid for Filled<'_> {
+        type Coord = Integer;
+        type Value = u8;
+
+        fn origin(&self) -> Vec2 {
+            self.0.origin()
+        }
+
+        fn dimensions(&self) -> Vec2 {
+            self.0.dimensions()
+        }
+
+        fn get(&self, p: Vec2) -> Option<u8> {
+            Grid::get(self.0, p).map(|risk| risk.unwrap_or(u8::MAX))
+        }
+    }
+
+    #[test]
+    fn wide_risks() {
+        // The total risk does not fit in an i16
+        let data = "9".repeat(4000);
+        assert_eq!(solve_one(&parse_map(&data).unwrap()), 3999 * 9);
+    }
+
+    #[test]
+    fn example_path() {
+        let map = parse_map(EXAMPLE).unwrap();
+        let (risk, path) = lowest_risk_path(&map, (0, 0), (9, 9)).unwrap();
+        assert_eq!(risk, 40);
+        assert_eq!(path.len(), 19);
+        assert_eq!((path[0], path[18]), ((0, 0), (9, 9)));
+
+        // The whole map is shown, with the path drawn over it
+        let rendered = render_path(&map, &path, Highlight::Marker);
+        assert_eq!(rendered[0], "*163751742");
+        assert!(rendered[9].ends_with('*'));
+        let marked: usize = rendered.iter().map(|row| row.matches('*').count()).sum();
+        assert_eq!(marked, path.len());
+
+        // The risk of every cell except the first adds up to the total
+        let total: Risk = path[1..].iter().map(|&p| map[p] as Risk).sum();
+        assert_eq!(total, risk);
+    }
+}
@@ -1,112 +1,72 @@
-#![feature(int_abs_diff)]
-
-use fnv::FnvHashMap;
-use fnv::FnvHashSet;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use std::iter::FromIterator;
-
-use common::Array2D;
-
-type Integer = i16;
-type Vec2 = (Integer, Integer);
-
-fn manhattan_distance(a: &Vec2, b: &Vec2) -> Integer {
-    let dx = a.0.abs_diff(b.0);
-    let dy = a.1.abs_diff(b.1);
-
-    (dx + dy) as Integer
-}
-
-fn a_star(input_at: impl Fn(Vec2) -> Option<u8>, start: Vec2, end: Vec2) -> Option<Integer> {
-    let start_risk = manhattan_distance(&start, &end);
-
-    let mut risks = FnvHashMap::from_iter([(start, Integer::from(0_u8))]);
-    let mut candidates = FnvHashSet::from_iter([start]);
-    let mut candidate_queue = BinaryHeap::from([Reverse((start_risk, start))]);
-
-    while let Some(Reverse((risk, v @ (x, y)))) = candidate_queue.pop() {
-        if v == end {
-            return Some(risk);
+use common::{input, solution, Grid};
+use problem_15::{lowest_risk_path, parse_map, render_path, tiled_map, Highlight, TILES};
+use std::io::IsTerminal;
+
+/// Print the lowest-risk path through the base and the tiled map in `path`.
+fn print_paths(path: &str, highlight: Highlight) -> Result<(), String> {
+    let name = solution::display_name(path);
+    let data = solution::read_input(path)?;
+    let map = input::normalize(&data)
+        .and_then(|data| parse_map(&data))
+        .map_err(|e| e.with_file(name).to_string())?;
+
+    for (part, tiles) in [("one", 1), ("two", TILES)] {
+        let map = tiled_map(&map, tiles);
+        let (width, height) = map.dimensions();
+        let (risk, route) =
+            lowest_risk_path(&map, (0, 0), (width - 1, height - 1)).ok_or("No path exists")?;
+
+        println!(
+            "Part {}: total risk {} over {} steps",
+            part,
+            risk,
+            route.len() - 1
+        );
+        for (x, y) in &route {
+            println!("{},{}", x, y);
         }
-
-        let neighbors = [(x, y - 1), (x - 1, y), (x + 1, y), (x, y + 1)];
-        for n in neighbors {
-            if let Some(edge_risk) = input_at(n) {
-                let estimated_risk = risks[&v] + edge_risk as Integer;
-                let e = risks.entry(n).or_insert(Integer::MAX);
-                if estimated_risk < *e {
-                    *e = estimated_risk;
-                    let heuristic = estimated_risk + manhattan_distance(&n, &end);
-                    if candidates.insert(n) {
-                        candidate_queue.push(Reverse((heuristic, n)));
-                    }
-                }
-            }
+        println!();
+        for row in render_path(&map, &route, highlight) {
+            println!("{}", row);
         }
-
-        candidates.remove(&v);
+        println!();
     }
 
-    None
-}
-
-fn solve_one(input: &Array2D<u8, Integer>) -> Integer {
-    let get_input_one = |v| input.get(v).copied();
-    a_star(get_input_one, (0, 0), (input.width - 1, input.height - 1)).expect("No path exists")
-}
-
-fn solve_two(input: &Array2D<u8, Integer>) -> Integer {
-    // The input data is repeated 4 times in each direction
-    let new_width = input.width * 5;
-    let new_height = input.height * 5;
-
-    // The value increases by the x and y tile number but rolls over from 9 to 1
-    let transform = |mut n: u8, x_tile, y_tile| {
-        n += x_tile as u8 + y_tile as u8;
-        n = n.saturating_sub(1);
-        n %= 9;
-        n += 1;
-        n
-    };
-
-    // Simulate tile expansion to save memory
-    let get_input_two = |(v_x, v_y)| {
-        let v = (v_x % input.width, v_y % input.height);
-        let x_tile = v_x / input.width;
-        let y_tile = v_y / input.height;
-
-        input
-            .get(v)
-            .copied()
-            .map(|input| transform(input, x_tile, y_tile))
-    };
-
-    a_star(get_input_two, (0, 0), (new_width - 1, new_height - 1)).expect("No path exists")
+    Ok(())
 }
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+    match args.iter().skip(1).position(|a| a == "--path") {
+        Some(i) => {
+            args.remove(i + 1);
+        }
+        None => {
+            solution::main(15, &problem_15::Problem);
+            return;
+        }
+    }
+
     if args.len() < 2 {
-        eprintln!("Usage: {} [problem_input]", args[0]);
+        eprintln!("Usage: {} --path problem_input... (- for stdin)", args[0]);
         std::process::exit(-1);
     }
 
-    let data = std::fs::read_to_string(&args[1]).expect("Unable to open input file");
-    let mut rows = data.split('\n').filter(|s| !s.is_empty()).peekable();
-
-    // Peek first element to ascertain width
-    let first = rows.peek().expect("Input is empty");
-    let width_one = first.len() as Integer;
-
-    let map = Array2D::from_iter(
-        rows.flat_map(|s| s.chars()).map(|c| c as u8 - b'0'),
-        width_one,
-    );
+    let highlight = if std::io::stdout().is_terminal() {
+        Highlight::Ansi
+    } else {
+        Highlight::Marker
+    };
 
-    let answer_one = solve_one(&map);
-    let answer_two = solve_two(&map);
+    let mut failed = false;
+    for path in &args[1..] {
+        if let Err(e) = print_paths(path, highlight) {
+            eprintln!("{}", e);
+            failed = true;
+        }
+    }
 
-    println!("{}", answer_one);
-    println!("{}", answer_two);
+    if failed {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,403 @@
+#[macro_use]
+extern crate lazy_static;
+
+use common::error::{Error, Result};
+use common::Solution;
+
+const BITS: usize = 4;
+
+#[derive(Copy, Clone)]
+enum OperatorType {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+struct Operator {
+    op_type: OperatorType,
+    subpackets: Vec<Packet>,
+}
+
+impl Operator {
+    // The operand counts are checked by parse_operator
+    fn eval(&self) -> usize {
+        use OperatorType::*;
+        let iter = self.subpackets.iter().map(|x| x.eval());
+        match self.op_type {
+            Sum => iter.sum(),
+            Product => iter.product(),
+            Minimum => iter.min().unwrap(),
+            Maximum => iter.max().unwrap(),
+            GreaterThan => (self.subpackets[0].eval() > self.subpackets[1].eval()) as usize,
+            LessThan => (self.subpackets[0].eval() < self.subpackets[1].eval()) as usize,
+            EqualTo => (self.subpackets[0].eval() == self.subpackets[1].eval()) as usize,
+        }
+    }
+}
+
+enum PacketType {
+    Lit(usize),
+    Op(Operator),
+}
+
+pub struct Packet {
+    version: u8,
+    packet_type: PacketType,
+}
+
+impl Packet {
+    pub fn sum_version(&self) -> usize {
+        use PacketType::*;
+        self.version as usize
+            + match &self.packet_type {
+                Lit(_) => 0,
+                Op(o) => o.subpackets.iter().map(|x| x.sum_version()).sum(),
+            }
+    }
+    pub fn eval(&self) -> usize {
+        use PacketType::*;
+        match &self.packet_type {
+            Lit(value) => *value,
+            Op(o) => o.eval(),
+        }
+    }
+}
+
+fn concat_usize(input: &[u8]) -> usize {
+    let bit = |x, n| (x >> n) & 0x1;
+    let bit_at = |i| bit(input[i / BITS], i % BITS);
+
+    let mut result = 0_usize;
+    let length = input.len() * BITS;
+    for i in 0..length {
+        result |= (bit_at(i) as usize) << i;
+    }
+
+    result
+}
+
+fn read_bits(input: &[u8], bit_pos: &mut usize, n_bits: usize) -> Option<Vec<u8>> {
+    let mut current_pos = *bit_pos;
+
+    // A read may end exactly at the last bit of the stream
+    if current_pos + n_bits > input.len() * BITS {
+        return None;
+    }
+
+    let bit = |x, n| (x >> n) & 0x1;
+    let bit_at = |i| bit(input[i / BITS], BITS - i % BITS - 1);
+
+    let result_length = n_bits.div_ceil(BITS);
+    let mut result = vec![0_u8; result_length];
+
+    let top_bits = n_bits % BITS;
+    for i in 0..top_bits {
+        let read_pos = current_pos + i;
+        let input_bit = bit_at(read_pos);
+
+        let out_byte = result_length - 1;
+        let out_bit = top_bits - i - 1;
+
+        result[out_byte] |= input_bit << out_bit;
+    }
+    current_pos += top_bits;
+
+    let remaining = n_bits - top_bits;
+    for i in 0..remaining {
+        let read_pos = current_pos + i;
+        let input_bit = bit_at(read_pos);
+
+        let out_byte = result_length - i / BITS - 2;
+        let out_bit = BITS - i % BITS - 1;
+        result[out_byte] |= input_bit << out_bit;
+    }
+    current_pos += remaining;
+
+    *bit_pos = current_pos;
+
+    Some(result)
+}
+
+fn operator_type(x: u8) -> OperatorType {
+    use OperatorType::*;
+
+    lazy_static! {
+        static ref TYPE_LOOKUP: Vec<OperatorType> = vec![
+            Sum,
+            Product,
+            Minimum,
+            Maximum,
+            Maximum, // Dummy
+            GreaterThan,
+            LessThan,
+            EqualTo];
+    }
+
+    TYPE_LOOKUP[x as usize]
+}
+
+/// Why a packet could not be parsed.
+enum Malformed {
+    /// The stream ended before the packet did
+    Truncated,
+    /// The packet starting at this bit position is invalid
+    Invalid(usize, &'static str),
+}
+
+type Parsed<T> = std::result::Result<T, Malformed>;
+
+fn read(input: &[u8], bit_pos: &mut usize, n_bits: usize) -> Parsed<Vec<u8>> {
+    read_bits(input, bit_pos, n_bits).ok_or(Malformed::Truncated)
+}
+
+fn parse_operator(
+    input: &[u8],
+    start: usize,
+    version: u8,
+    op_type: u8,
+    position: &mut usize,
+) -> Parsed<Packet> {
+    use OperatorType::*;
+
+    let length_type_id = read(input, position, 1)?;
+
+    let limit_type = if length_type_id[0] == 0 {
+        let total_length_bits = read(input, position, 15)?;
+        Limit::Pos(*position + concat_usize(&total_length_bits))
+    } else {
+        let max_packet_bits = read(input, position, 11)?;
+        Limit::Num(concat_usize(&max_packet_bits))
+    };
+
+    let subpackets = parse(input, position, &limit_type)?;
+    if let Limit::Pos(end) = limit_type {
+        if *position > end {
+            return Err(Malformed::Invalid(start, "Subpackets overrun their length"));
+        }
+    }
+
+    let op_type = operator_type(op_type);
+    match (op_type, subpackets.len()) {
+        (Minimum | Maximum, 0) => Err(Malformed::Invalid(start, "Expected at least one operand")),
+        (GreaterThan | LessThan | EqualTo, n) if n != 2 => {
+            Err(Malformed::Invalid(start, "Expected two operands"))
+        }
+        _ => Ok(Packet {
+            version,
+            packet_type: PacketType::Op(Operator {
+                op_type,
+                subpackets,
+            }),
+        }),
+    }
+}
+
+enum Limit {
+    Pos(usize),
+    Num(usize),
+}
+
+impl Limit {
+    fn predicate(&self, position: usize, num: usize) -> bool {
+        match self {
+            Limit::Pos(p) => position < *p,
+            Limit::Num(n) => num < *n,
+        }
+    }
+}
+
+fn parse_packet(input: &[u8], pos: &mut usize) -> Parsed<Packet> {
+    let start = *pos;
+    let v = read(input, pos, 3)?;
+    let t = read(input, pos, 3)?;
+
+    match t[0] {
+        4 => {
+            let mut literal = Vec::new();
+            loop {
+                let tmp = read(input, pos, 5)?;
+                literal.push(tmp[0]);
+                if tmp[1] == 0 {
+                    break;
+                }
+            }
+            literal.reverse();
+            Ok(Packet {
+                version: v[0],
+                packet_type: PacketType::Lit(concat_usize(&literal)),
+            })
+        }
+        op_type => parse_operator(input, start, v[0], op_type, pos),
+    }
+}
+
+fn parse(input: &[u8], position: &mut usize, max: &Limit) -> Parsed<Vec<Packet>> {
+    let mut result = Vec::new();
+    while max.predicate(*position, result.len()) {
+        result.push(parse_packet(input, position)?);
+    }
+
+    Ok(result)
+}
+
+/// Whether every bit from `position` onwards is zero.
+fn only_padding(input: &[u8], position: usize) -> bool {
+    (position..input.len() * BITS).all(|i| input[i / BITS] & (1 << (BITS - i % BITS - 1)) == 0)
+}
+
+fn parse_hex(c: char) -> Option<u8> {
+    c.to_digit(16).map(|d| d as u8)
+}
+
+pub fn parse_transmission(data: &str) -> Result<Vec<Packet>> {
+    let trimmed = data.trim_end();
+    let stream: Vec<u8> = trimmed
+        .char_indices()
+        .map(|(i, c)| {
+            parse_hex(c).ok_or_else(|| {
+                Error::locate(data, &trimmed[i..i + c.len_utf8()], "Expected hex digit")
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    // Trailing zero bits pad the transmission to a whole number of hex digits
+    let mut packets = Vec::new();
+    let mut position = 0;
+    while !only_padding(&stream, position) {
+        let packet = parse_packet(&stream, &mut position).map_err(|e| match e {
+            Malformed::Truncated => Error::at_end(data, "Transmission ends inside a packet"),
+            Malformed::Invalid(bit, message) => {
+                let i = bit / BITS;
+                Error::locate(data, &trimmed[i..i + 1], message)
+            }
+        })?;
+        packets.push(packet);
+    }
+    if packets.is_empty() {
+        return Err(Error::at_end(data, "No packets found"));
+    }
+
+    Ok(packets)
+}
+
+pub fn solve_one(packets: &[Packet]) -> usize {
+    packets.iter().map(|p| p.sum_version()).sum::<usize>()
+}
+
+pub fn solve_two(packets: &[Packet]) -> usize {
+    packets.first().expect("Must have one packet").eval()
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<Packet>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_transmission(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_to_end() {
+        let stream = [0xA, 0x5];
+        let mut position = 2;
+        assert_eq!(read_bits(&stream, &mut position, 6), Some(vec![0x5, 0x2]));
+        assert_eq!(position, 8);
+        assert_eq!(read_bits(&stream, &mut position, 1), None);
+        assert_eq!(position, 8);
+    }
+
+    #[test]
+    fn example_literal() {
+        let packets = parse_transmission("D2FE28").unwrap();
+        assert_eq!(packets[0].eval(), 2021);
+    }
+
+    #[test]
+    fn example_one() {
+        let version_sum = |s| solve_one(&parse_transmission(s).unwrap());
+        assert_eq!(version_sum("8A004A801A8002F478"), 16);
+        assert_eq!(version_sum("620080001611562C8802118E34"), 12);
+        assert_eq!(version_sum("C0015000016115A2E0802F182340"), 23);
+        assert_eq!(version_sum("A0016C880162017C3686B18A3D4780"), 31);
+    }
+
+    #[test]
+    fn example_two() {
+        let value = |s| solve_two(&parse_transmission(s).unwrap());
+        assert_eq!(value("C200B40A82"), 3);
+        assert_eq!(value("04005AC33890"), 54);
+        assert_eq!(value("880086C3E88112"), 7);
+        assert_eq!(value("CE00C43D881120"), 9);
+        assert_eq!(value("D8005AC2A8F0"), 1);
+        assert_eq!(value("F600BC2D8F"), 0);
+        assert_eq!(value("9C005AC2F8F0"), 0);
+        assert_eq!(value("9C0141080250320F1802104A08"), 1);
+    }
+
+    #[test]
+    fn invalid_transmission() {
+        let e = parse_transmission("D2FG28\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 4, "G"));
+
+        let e = parse_transmission("\n").err().unwrap();
+        assert_eq!(e.message, "No packets found");
+    }
+
+    #[test]
+    fn truncated_transmission() {
+        let e = parse_transmission("D2FE").err().unwrap();
+        assert_eq!(e.message, "Transmission ends inside a packet");
+
+        // The operator promises two subpackets but only has one
+        let e = parse_transmission("02008408\n").err().unwrap();
+        assert_eq!(e.message, "Transmission ends inside a packet");
+        assert_eq!(e.line, 2);
+    }
+
+    #[test]
+    fn wrong_operand_count() {
+        // Minimum of nothing
+        let e = parse_transmission("0A000").err().unwrap();
+        assert_eq!(
+            (e.column, e.message.as_str()),
+            (1, "Expected at least one operand")
+        );
+
+        // Less than with three operands, nested in a sum
+        let e = parse_transmission("020046803102208418").err().unwrap();
+        assert_eq!((e.column, e.message.as_str()), (5, "Expected two operands"));
+        let packets = parse_transmission("020046802102208").unwrap();
+        assert_eq!(solve_two(&packets), 1);
+    }
+
+    #[test]
+    fn crlf_input() {
+        use common::{Answer, Runnable};
+
+        let outcome = Problem.run("\u{feff}8A004A801A8002F478\r\n\r\n").unwrap();
+        assert_eq!(outcome.answers[0], Answer::from(16_usize));
+
+        let e = Problem.run("8A004A801A8002F478\r\n\u{0}").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
@@ -0,0 +1,149 @@
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+pub type Integer = i16;
+
+pub struct TargetArea {
+    xmin: Integer,
+    xmax: Integer,
+    ymin: Integer,
+    ymax: Integer,
+}
+
+pub fn parse_target(data: &str) -> Result<TargetArea> {
+    let input = parse::lines(data)
+        .next()
+        .ok_or_else(|| Error::at_end(data, "Input is empty"))?;
+
+    let re = Regex::new(
+        r".+x=(?P<xmin>[0-9-]+)\.\.(?P<xmax>[0-9-]+).+y=(?P<ymin>[0-9-]+)\.\.(?P<ymax>[0-9-]+)",
+    )
+    .unwrap();
+
+    let caps = re
+        .captures(input)
+        .ok_or_else(|| Error::locate(data, input, "Expected target area"))?;
+    let parse_coord = |coord| parse::integer::<Integer>(data, caps.name(coord).unwrap().as_str());
+
+    Ok(TargetArea {
+        xmin: parse_coord("xmin")?,
+        xmax: parse_coord("xmax")?,
+        ymin: parse_coord("ymin")?,
+        ymax: parse_coord("ymax")?,
+    })
+}
+
+// Arithmetic sum over y
+fn pos_after_n(v0y: Integer, n: Integer) -> Integer {
+    (n * (2 * v0y + 1) - n * n) / 2
+}
+
+pub fn valid_velocities(area: &TargetArea) -> BTreeSet<(Integer, Integer)> {
+    let &TargetArea {
+        xmin,
+        xmax,
+        ymin,
+        ymax,
+    } = area;
+
+    // Inversion of the arithmetic sum over y
+    let n_limit = |p0, v0| {
+        let b = 2.0 * v0 as f32 + 1.0;
+        let left = 0.5 * b;
+        let right: f32 = 0.5 * (b * b - 8.0 * p0 as f32).sqrt();
+        (left + right).ceil() as Integer
+    };
+
+    // Arithmetic sum over x
+    let pos_after_n_clamped = |v0x, n| {
+        if n >= v0x {
+            v0x * (v0x + 1) / 2
+        } else {
+            pos_after_n(v0x, n)
+        }
+    };
+
+    // Inversion of the arithmetic sum over x
+    let min_vx = (-0.5 + (0.25 + xmin as f32).sqrt()).floor() as Integer;
+    let max_vx = xmax;
+    let min_vy = ymin;
+    let max_vy = ymin.abs();
+
+    let mut valid_vs = BTreeSet::new();
+    for vy in min_vy..(max_vy + 1) {
+        for vx in min_vx..(max_vx + 1) {
+            for n in 0..(n_limit(ymin, vy) + 1) {
+                let x = pos_after_n_clamped(vx, n);
+                if x > xmax {
+                    break;
+                }
+                let y = pos_after_n(vy, n);
+
+                if x >= xmin && x <= xmax && y >= ymin && y <= ymax {
+                    valid_vs.insert((vy, vx));
+                }
+            }
+        }
+    }
+
+    valid_vs
+}
+
+pub fn solve_one(area: &TargetArea) -> Integer {
+    valid_velocities(area)
+        .last()
+        .map(|&(vy, _)| pos_after_n(vy, vy))
+        .unwrap()
+}
+
+pub fn solve_two(area: &TargetArea) -> usize {
+    valid_velocities(area).len()
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = TargetArea;
+    type AnswerOne = Integer;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_target(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_target(EXAMPLE).unwrap()), 45);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_target(EXAMPLE).unwrap()), 112);
+    }
+
+    #[test]
+    fn invalid_target() {
+        let e = parse_target("target area: x=20..30, y=-10..-5-\n")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 31, "-5-"));
+    }
+}
@@ -192,6 +192,7 @@ impl SnailNumber {
         false
     }
 
+    #[allow(clippy::manual_div_ceil)]
     fn split(&mut self) -> bool {
         use SnailNumberType::*;
         match self.data {
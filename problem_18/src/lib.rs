@@ -0,0 +1,111 @@
+pub mod ast;
+
+use ast::SnailNumber;
+use common::error::{Error, Result};
+use common::parse;
+use common::Solution;
+use itertools::Itertools;
+
+#[macro_use]
+extern crate lalrpop_util;
+
+use lalrpop_util::ParseError;
+
+lalrpop_mod!(
+    #[allow(clippy::all)]
+    snailnumbers
+);
+
+pub fn parse_numbers(data: &str) -> Result<Vec<Box<SnailNumber>>> {
+    let parser = snailnumbers::SnailNumberParser::new();
+    let numbers: Vec<_> = parse::lines(data)
+        .map(|s| {
+            parser.parse(s).map_err(|e| {
+                let location = match e {
+                    ParseError::InvalidToken { location } => location,
+                    ParseError::UnrecognizedEOF { location, .. } => location,
+                    ParseError::UnrecognizedToken { token, .. } => token.0,
+                    ParseError::ExtraToken { token } => token.0,
+                    ParseError::User { .. } => 0,
+                };
+                Error::locate(data, &s[location..], "Unable to parse snail number")
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    if numbers.is_empty() {
+        return Err(Error::at_end(data, "No snail numbers found"));
+    }
+
+    Ok(numbers)
+}
+
+pub fn solve_one(numbers: &[Box<SnailNumber>]) -> usize {
+    numbers
+        .iter()
+        .cloned()
+        .reduce(|a, b| a + b)
+        .unwrap()
+        .magnitude()
+}
+
+pub fn solve_two(numbers: &[Box<SnailNumber>]) -> usize {
+    numbers.iter().permutations(2).fold(0_usize, |acc, v| {
+        let a = v[0].clone();
+        let b = v[1].clone();
+        std::cmp::max(acc, (a + b).magnitude())
+    })
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Vec<Box<SnailNumber>>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_numbers(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_magnitude() {
+        let magnitude = |s| parse_numbers(s).unwrap()[0].magnitude();
+        assert_eq!(magnitude("[[1,2],[[3,4],5]]"), 143);
+        assert_eq!(
+            magnitude("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"),
+            3488
+        );
+    }
+
+    #[test]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_numbers(EXAMPLE).unwrap()), 4140);
+    }
+
+    #[test]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_numbers(EXAMPLE).unwrap()), 3993);
+    }
+
+    #[test]
+    fn invalid_number() {
+        let e = parse_numbers("[1,2]\n[[3,4],x]\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 8, "x]"));
+    }
+}
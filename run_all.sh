@@ -4,9 +4,4 @@ set -e
 
 cargo build --release
 
-for i in problem*; do
-    echo $i
-    echo ----------------
-    time target/release/$i $i/input.txt
-    echo ----------------
-done
+target/release/aoc bench --json bench.json "$@"
@@ -0,0 +1,56 @@
+use common::error::Result;
+use common::{Array2D, Solution};
+
+pub type Integer = i16;
+
+pub fn parse_input(data: &str) -> Result<Array2D<'static, u8, Integer>> {
+    Array2D::parse_digits(data, data)
+}
+
+// Placeholder answers, so that an unsolved day shows up as a wrong answer in the runner
+pub fn solve_one(_input: &Array2D<u8, Integer>) -> usize {
+    0
+}
+
+pub fn solve_two(_input: &Array2D<u8, Integer>) -> usize {
+    0
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Input = Array2D<'static, u8, Integer>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input> {
+        parse_input(data)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::AnswerOne {
+        solve_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::AnswerTwo {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn example_one() {
+        assert_eq!(solve_one(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "fill in example.txt and the expected answer"]
+    fn example_two() {
+        assert_eq!(solve_two(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+}
@@ -0,0 +1,38 @@
+mod new_day;
+
+use std::path::{Path, PathBuf};
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask must live inside the workspace")
+        .to_path_buf()
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} new-day DAY", program);
+    std::process::exit(-1);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let program = "cargo xtask";
+
+    match args.get(1).map(String::as_str) {
+        Some("new-day") if args.len() == 3 => {
+            let day = match new_day::parse_day(&args[2]) {
+                Ok(day) => day,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    usage(program);
+                }
+            };
+
+            if let Err(e) = new_day::new_day(&workspace_root(), day) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        _ => usage(program),
+    }
+}
@@ -0,0 +1,309 @@
+use std::fs;
+use std::path::Path;
+
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or(format!("Invalid day \"{}\", expected 1-25", s))
+}
+
+fn crate_name(day: u8) -> String {
+    format!("problem_{:02}", day)
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> String {
+    format!("{}: {}", path.display(), e)
+}
+
+/// Add `name` to the `members` list of the workspace manifest, keeping the list sorted.
+/// Returns None if it is already a member.
+fn add_workspace_member(manifest: &str, name: &str) -> Result<Option<String>, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("Workspace manifest has no members list")?;
+    let list_start = start + "members = [".len();
+    let list_end = list_start
+        + manifest[list_start..]
+            .find(']')
+            .ok_or("Unterminated members list")?;
+
+    let mut members: Vec<&str> = manifest[list_start..list_end]
+        .split(',')
+        .map(|s| s.trim().trim_matches('"'))
+        .filter(|s| !s.is_empty())
+        .collect();
+    if members.contains(&name) {
+        return Ok(None);
+    }
+    members.push(name);
+    members.sort_unstable();
+
+    let list = members
+        .iter()
+        .map(|m| format!("    \"{}\"", m))
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    Ok(Some(format!(
+        "{}\n{}\n{}",
+        &manifest[..list_start],
+        list,
+        &manifest[list_end..]
+    )))
+}
+
+/// Add a path dependency on `name` to the runner's manifest, next to the other days in order.
+/// Returns None if the dependency already exists.
+fn add_runner_dependency(manifest: &str, name: &str) -> Result<Option<String>, String> {
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let section = lines
+        .iter()
+        .position(|l| l.trim() == "[dependencies]")
+        .ok_or("Runner manifest has no [dependencies] section")?;
+
+    // Insert before the first later day, after the last earlier day, or else at the end
+    let mut before_day = None;
+    let mut after_day = None;
+    let mut end = section + 1;
+    for (i, line) in lines.iter().enumerate().skip(section + 1) {
+        if line.starts_with('[') {
+            break;
+        }
+        let key = line.split('=').next().unwrap_or("").trim();
+        if key == name {
+            return Ok(None);
+        }
+        if key.starts_with("problem_") {
+            if key > name {
+                before_day.get_or_insert(i);
+            } else {
+                after_day = Some(i + 1);
+            }
+        }
+        if !line.trim().is_empty() {
+            end = i + 1;
+        }
+    }
+
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    lines.insert(before_day.or(after_day).unwrap_or(end), &dependency);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// Register `day` in the runner's table of days, which is kept in day order.
+/// Returns None if the day is already registered.
+fn add_runner_day(days: &str, day: u8) -> Result<Option<String>, String> {
+    let header = "pub static DAYS: &[Day] = &[\n";
+    let start = days.find(header).ok_or("Day registry not found")? + header.len();
+    let end = start
+        + days[start..]
+            .find("];")
+            .ok_or("Unterminated day registry")?;
+
+    let mut numbers: Vec<u8> = days[start..end]
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("number: "))
+        .map(|n| n.trim_end_matches(',').parse::<u8>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Invalid day number in registry: {}", e))?;
+    if numbers.contains(&day) {
+        return Ok(None);
+    }
+    numbers.push(day);
+    numbers.sort_unstable();
+
+    let entries: String = numbers
+        .iter()
+        .map(|&n| {
+            format!(
+                "    Day {{\n        number: {},\n        solution: &{}::Problem,\n    }},\n",
+                n,
+                crate_name(n)
+            )
+        })
+        .collect();
+
+    Ok(Some(format!(
+        "{}{}{}",
+        &days[..start],
+        entries,
+        &days[end..]
+    )))
+}
+
+/// Copy the template into `target` for `day`. The placeholder `{{crate}}` becomes the crate name
+/// and `{{day}}` the day number; nothing else is changed.
+fn copy_template(template: &Path, target: &Path, day: u8) -> Result<(), String> {
+    fs::create_dir(target).map_err(|e| io_error(target, e))?;
+
+    for entry in fs::read_dir(template).map_err(|e| io_error(template, e))? {
+        let entry = entry.map_err(|e| io_error(template, e))?;
+        let source = entry.path();
+        let destination = target.join(entry.file_name());
+
+        if source.is_dir() {
+            copy_template(&source, &destination, day)?;
+        } else {
+            let contents = fs::read_to_string(&source).map_err(|e| io_error(&source, e))?;
+            let contents = contents
+                .replace("{{crate}}", &crate_name(day))
+                .replace("{{day}}", &day.to_string());
+            fs::write(&destination, contents).map_err(|e| io_error(&destination, e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrite the file at `path` with `edit`, if it reports a change.
+fn update_file(
+    path: &Path,
+    edit: impl FnOnce(&str) -> Result<Option<String>, String>,
+) -> Result<bool, String> {
+    let contents = fs::read_to_string(path).map_err(|e| io_error(path, e))?;
+    match edit(&contents).map_err(|e| format!("{}: {}", path.display(), e))? {
+        Some(updated) => {
+            fs::write(path, updated).map_err(|e| io_error(path, e))?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Create `problem_NN` from the template and wire it into the workspace and the runner.
+///
+/// An existing day is never overwritten; running this again only adds whatever registration is
+/// still missing.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    let name = crate_name(day);
+    let target = root.join(&name);
+
+    if target.exists() {
+        println!("{} already exists, not overwriting it", name);
+    } else {
+        copy_template(&root.join("template"), &target, day)?;
+        println!("Created {} from template", name);
+    }
+
+    for fixture in ["example.txt", "input.txt"] {
+        let path = target.join(fixture);
+        if !path.exists() {
+            fs::write(&path, "").map_err(|e| io_error(&path, e))?;
+            println!("Created empty {}/{}", name, fixture);
+        }
+    }
+
+    if update_file(&root.join("Cargo.toml"), |m| add_workspace_member(m, &name))? {
+        println!("Added {} to the workspace members", name);
+    }
+    if update_file(&root.join("aoc/Cargo.toml"), |m| {
+        add_runner_dependency(m, &name)
+    })? {
+        println!("Added {} to the runner dependencies", name);
+    }
+    if update_file(&root.join("aoc/src/days.rs"), |d| add_runner_day(d, day))? {
+        println!("Registered day {} in the runner", day);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str =
+        "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"problem_01\",\n    \"problem_03\"\n]\n";
+
+    const RUNNER: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\ncommon = { path = \"../common\" }\nproblem_01 = { path = \"../problem_01\" }\nproblem_03 = { path = \"../problem_03\" }\nserde = \"1.0\"\n";
+
+    const DAYS: &str = "pub static DAYS: &[Day] = &[\n    Day {\n        number: 3,\n        solution: &problem_03::Problem,\n    },\n];\n\npub fn find() {}\n";
+
+    #[test]
+    fn workspace_member_sorted() {
+        let updated = add_workspace_member(WORKSPACE, "problem_02")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"problem_01\",\n    \"problem_02\",\n    \"problem_03\"\n]\n"
+        );
+        assert_eq!(add_workspace_member(&updated, "problem_02"), Ok(None));
+    }
+
+    #[test]
+    fn runner_dependency_sorted() {
+        let updated = add_runner_dependency(RUNNER, "problem_02")
+            .unwrap()
+            .unwrap();
+        assert!(updated.contains(
+            "problem_01 = { path = \"../problem_01\" }\nproblem_02 = { path = \"../problem_02\" }\nproblem_03"
+        ));
+        assert_eq!(add_runner_dependency(&updated, "problem_02"), Ok(None));
+
+        let updated = add_runner_dependency(RUNNER, "problem_04")
+            .unwrap()
+            .unwrap();
+        assert!(updated.contains("problem_03 = { path = \"../problem_03\" }\nproblem_04 = { path = \"../problem_04\" }\nserde"));
+    }
+
+    #[test]
+    fn runner_day_sorted() {
+        let updated = add_runner_day(DAYS, 1).unwrap().unwrap();
+        assert_eq!(
+            updated,
+            "pub static DAYS: &[Day] = &[\n    Day {\n        number: 1,\n        solution: &problem_01::Problem,\n    },\n    Day {\n        number: 3,\n        solution: &problem_03::Problem,\n    },\n];\n\npub fn find() {}\n"
+        );
+        assert_eq!(add_runner_day(&updated, 1), Ok(None));
+        assert_eq!(add_runner_day(&updated, 3), Ok(None));
+    }
+
+    #[test]
+    fn new_day_is_idempotent() {
+        let root = std::env::temp_dir().join(format!("xtask-new-day-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("template/src")).unwrap();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("template/Cargo.toml"), "name = \"{{crate}}\"\n").unwrap();
+        fs::write(
+            root.join("template/src/main.rs"),
+            "main({{day}}, &{{crate}}::Problem) // 0xx, day xx\n",
+        )
+        .unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+
+        new_day(&root, 2).unwrap();
+        let read = |p: &str| fs::read_to_string(root.join(p)).unwrap();
+        assert_eq!(read("problem_02/Cargo.toml"), "name = \"problem_02\"\n");
+        assert_eq!(
+            read("problem_02/src/main.rs"),
+            "main(2, &problem_02::Problem) // 0xx, day xx\n"
+        );
+        assert_eq!(read("problem_02/input.txt"), "");
+        assert_eq!(read("problem_02/example.txt"), "");
+
+        // A second run must not touch the day's files or duplicate any registration
+        fs::write(root.join("problem_02/src/main.rs"), "solved\n").unwrap();
+        let manifests = (
+            read("Cargo.toml"),
+            read("aoc/Cargo.toml"),
+            read("aoc/src/days.rs"),
+        );
+        new_day(&root, 2).unwrap();
+        assert_eq!(read("problem_02/src/main.rs"), "solved\n");
+        assert_eq!(
+            (
+                read("Cargo.toml"),
+                read("aoc/Cargo.toml"),
+                read("aoc/src/days.rs")
+            ),
+            manifests
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}